@@ -4,15 +4,12 @@ fn main() {
     // literally: pikachu transliterated
     let subject = "피카츄";
 
-    // Korean marks the topic of the sentence with a post position
+    // Korean marks the subject of the sentence with a post position
     // particle: 이 follows consonants, and 가 follows vowels.
-    let post_position = match hangeul::ends_in_consonant(subject).unwrap() {
-        true => "이",
-        false => "가",
-    };
+    let subject = hangeul::josa::attach(subject, hangeul::josa::Josa::IGa).unwrap();
 
     // -> A wild pikachu has appeared!
-    let sentence = format!("야생의 {}{} 나타났다!", subject, post_position);
+    let sentence = format!("야생의 {} 나타났다!", subject);
     println!("{}", sentence); // 야생의 피카츄가 나타났다!
 
     // get_lead is an alias of get_choseong, to get the first character
@@ -0,0 +1,199 @@
+//! Amounts of money written out the way contracts, invoices and bank slips ask
+//! for: 금 일백이십삼만사천원정. Spelling out every digit, 일 included, keeps the
+//! amount from being altered afterwards.
+use crate::errors::*;
+use crate::number::{self, SINO_DIGITS, SINO_GROUPS};
+
+/// How an amount is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// 금 일백이십삼만사천원정
+    Formal,
+    /// 金 壹佰貳拾參萬肆仟圓整, with the anti-forgery numerals (갖은자)
+    Hanja,
+    /// 123만 4,000원
+    Mixed,
+}
+
+const HANJA_DIGITS: [&str; 10] = ["零", "壹", "貳", "參", "肆", "伍", "陸", "柒", "捌", "玖"];
+
+const HANJA_GROUPS: [&str; 5] = ["", "萬", "億", "兆", "京"];
+
+/// Splits the amount into groups of four digits, lowest first.
+fn groups(amount: u64) -> Vec<u64> {
+    let mut groups = Vec::new();
+    let mut rest = amount;
+    while rest > 0 {
+        groups.push(rest % 10000);
+        rest /= 10000;
+    }
+    groups
+}
+
+/// Spells out every digit with its unit, keeping the 일: 일천일백일십일.
+fn spell(amount: u64, digits: &[&str; 10], units: &[&str; 3], group_units: &[&str; 5]) -> String {
+    if amount == 0 {
+        return digits[0].to_string();
+    }
+
+    let mut spelled = Vec::new();
+    for (group, group_unit) in groups(amount).iter().zip(group_units.iter()) {
+        if *group == 0 {
+            continue;
+        }
+
+        let mut read = String::new();
+        for (digit, unit) in [group / 1000, group / 100 % 10, group / 10 % 10]
+            .iter()
+            .zip(units.iter())
+        {
+            if *digit != 0 {
+                read.push_str(digits[*digit as usize]);
+                read.push_str(unit);
+            }
+        }
+        if group % 10 != 0 {
+            read.push_str(digits[(group % 10) as usize]);
+        }
+        read.push_str(group_unit);
+        spelled.push(read);
+    }
+    spelled.reverse();
+
+    spelled.concat()
+}
+
+/// Writes a group of digits with thousands separators: 2,345.
+fn separated(group: u64) -> String {
+    match group {
+        0..=999 => group.to_string(),
+        _ => format!("{},{:03}", group / 1000, group % 1000),
+    }
+}
+
+/// Writes an amount of won in the given style.
+///
+/// ```rust
+/// use hangeul::amount::{format, Style};
+///
+/// assert_eq!("금 일백이십삼만사천원정", format(1_234_000, Style::Formal));
+/// assert_eq!("金 壹佰貳拾參萬肆仟圓整", format(1_234_000, Style::Hanja));
+/// assert_eq!("1억 2,345만 원", format(123_450_000, Style::Mixed));
+/// assert_eq!("123만 4,000원", format(1_234_000, Style::Mixed));
+/// ```
+pub fn format(amount: u64, style: Style) -> String {
+    match style {
+        Style::Formal => format!(
+            "금 {}원정",
+            spell(amount, &SINO_DIGITS, &["천", "백", "십"], &SINO_GROUPS)
+        ),
+        Style::Hanja => format!(
+            "金 {}圓整",
+            spell(amount, &HANJA_DIGITS, &["仟", "佰", "拾"], &HANJA_GROUPS)
+        ),
+        Style::Mixed => {
+            if amount == 0 {
+                return "0원".to_string();
+            }
+
+            let mut parts: Vec<String> = groups(amount)
+                .iter()
+                .zip(SINO_GROUPS.iter())
+                .filter(|(group, _)| **group != 0)
+                .map(|(group, unit)| format!("{}{}", separated(*group), unit))
+                .collect();
+            parts.reverse();
+
+            // 원 is spaced after a unit, but sits right after digits
+            match amount % 10000 {
+                0 => format!("{} 원", parts.join(" ")),
+                _ => format!("{}원", parts.join(" ")),
+            }
+        }
+    }
+}
+
+/// Hangeul for the Hanja numerals, both the anti-forgery ones and the plain.
+fn hanja_numeral(c: char) -> Option<char> {
+    let hangeul = match c {
+        '零' => '영',
+        '壹' | '一' => '일',
+        '貳' | '二' => '이',
+        '參' | '三' => '삼',
+        '肆' | '四' => '사',
+        '伍' | '五' => '오',
+        '陸' | '六' => '육',
+        '柒' | '七' => '칠',
+        '捌' | '八' => '팔',
+        '玖' | '九' => '구',
+        '拾' | '十' => '십',
+        '佰' | '百' => '백',
+        '仟' | '千' => '천',
+        '萬' => '만',
+        '億' => '억',
+        '兆' => '조',
+        '京' => '경',
+        _ => return None,
+    };
+    Some(hangeul)
+}
+
+/// Parses an amount written in any of the styles, or plainly as in "만 오천 원".
+/// Errors with the char position of the first thing that can't be read.
+///
+/// ```rust
+/// use hangeul::amount::parse;
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok(1_234_000), parse("금 일백이십삼만사천원정"));
+/// assert_eq!(Ok(1_234_000), parse("金 壹佰貳拾參萬肆仟圓整"));
+/// assert_eq!(Ok(123_450_000), parse("1억 2,345만 원"));
+/// assert_eq!(Err(HangeulError::InvalidNumber(5)), parse("금 일백이백원정"));
+/// ```
+pub fn parse(written: &str) -> Result<u64> {
+    let mut chars: Vec<char> = written
+        .chars()
+        .map(|c| hanja_numeral(c).unwrap_or(c))
+        .collect();
+
+    // blank out the prefix and the suffix, so that positions stay the same
+    let start = chars.iter().position(|c| !c.is_whitespace()).unwrap_or(0);
+    if matches!(chars.get(start), Some('금' | '金')) {
+        chars[start] = ' ';
+    }
+    let end = chars.len() - chars.iter().rev().take_while(|c| c.is_whitespace()).count();
+    let suffixes: [&[char]; 6] = [
+        &['원', '정'],
+        &['圓', '整'],
+        &['정'],
+        &['整'],
+        &['원'],
+        &['圓'],
+    ];
+    if let Some(suffix) = suffixes
+        .iter()
+        .find(|suffix| chars[..end].ends_with(suffix))
+    {
+        chars[end - suffix.len()..end]
+            .iter_mut()
+            .for_each(|c| *c = ' ');
+    }
+
+    match number::parse_magnitude(&chars.iter().collect::<String>())? {
+        (true, amount) if amount != 0 => Err(HangeulError::OutOfRange),
+        (_, amount) => Ok(amount),
+    }
+}
+
+/// Checks that the written amount is the given one. Errors if the written amount
+/// can't be read.
+///
+/// ```rust
+/// use hangeul::amount::validate;
+///
+/// assert_eq!(Ok(true), validate("금 일백이십삼만사천원정", 1_234_000));
+/// assert_eq!(Ok(false), validate("금 일백이십삼만원정", 1_234_000));
+/// ```
+pub fn validate(written: &str, amount: u64) -> Result<bool> {
+    Ok(parse(written)? == amount)
+}
@@ -0,0 +1,270 @@
+//! Archaic Hangeul (옛한글) support.
+//!
+//! The rest of the crate only deals with the modern jamo that can be precomposed
+//! into syllables. Old jamo such as ㆍ, ㅿ, ㆁ and ㆆ, the rest of the Hangul Jamo
+//! block and the Jamo Extended-A (`A960`–`A97F`) and Extended-B (`D7B0`–`D7FF`)
+//! blocks are only understood by the functions of this module. Old syllables can't
+//! be precomposed, so they are written as sequences of conjoining jamo.
+use crate::constants::*;
+use crate::normalize::{to_nfc, to_nfd};
+
+/// The position of a conjoining jamo in a syllable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Choseong,
+    Jungseong,
+    Jongseong,
+}
+
+/// The position of a conjoining jamo, archaic jamo and fillers included.
+///
+/// ```rust
+/// use hangeul::archaic::{position, Position};
+///
+/// assert_eq!(Some(Position::Choseong), position('ᄀ' as u32));
+/// assert_eq!(Some(Position::Jungseong), position('ᆞ' as u32)); // arae-a
+/// assert_eq!(Some(Position::Jongseong), position('\u{D7F9}' as u32));
+/// assert_eq!(None, position('ㄱ' as u32));
+/// ```
+pub fn position(code: u32) -> Option<Position> {
+    match code {
+        CHOSEONG_START..=CHOSEONG_FILLER | JAMO_EXTENDED_A_START..=JAMO_EXTENDED_A_END => {
+            Some(Position::Choseong)
+        }
+        JUNGSEONG_FILLER..=OLD_JUNGSEONG_END
+        | EXTENDED_JUNGSEONG_START..=EXTENDED_JUNGSEONG_END => Some(Position::Jungseong),
+        JONGSEONG_START..=OLD_JONGSEONG_END | EXTENDED_JONGSEONG_START..=EXTENDED_JONGSEONG_END => {
+            Some(Position::Jongseong)
+        }
+        _ => None,
+    }
+}
+
+/// Checks if the u32 is an archaic jamo, in any of the conjoining or compatibility blocks.
+///
+/// ```rust
+/// use hangeul::archaic::is_old_jamo;
+///
+/// assert_eq!(true, is_old_jamo('ㆍ' as u32));
+/// assert_eq!(true, is_old_jamo('ᅀ' as u32));
+/// assert_eq!(true, is_old_jamo('\u{A960}' as u32));
+/// assert_eq!(false, is_old_jamo('ㄱ' as u32));
+/// ```
+pub fn is_old_jamo(code: u32) -> bool {
+    matches!(
+        code,
+        OLD_CHOSEONG_START..=OLD_CHOSEONG_END
+            | OLD_JUNGSEONG_START..=OLD_JUNGSEONG_END
+            | OLD_JONGSEONG_START..=OLD_JONGSEONG_END
+            | OLD_COMPAT_JAMO_START..=OLD_COMPAT_JAMO_END
+            | JAMO_EXTENDED_A_START..=JAMO_EXTENDED_A_END
+            | EXTENDED_JUNGSEONG_START..=EXTENDED_JUNGSEONG_END
+            | EXTENDED_JONGSEONG_START..=EXTENDED_JONGSEONG_END
+    )
+}
+
+/// Like `hangeul::is_hangeul`, but also accepting the Jamo Extended-A and -B blocks.
+///
+/// ```rust
+/// use hangeul::archaic;
+///
+/// assert_eq!(false, hangeul::is_hangeul(0xA960));
+/// assert_eq!(true, archaic::is_hangeul(0xA960));
+/// assert_eq!(true, archaic::is_hangeul('한' as u32));
+/// ```
+pub fn is_hangeul(code: u32) -> bool {
+    crate::is_hangeul(code)
+        || matches!(
+            code,
+            JAMO_EXTENDED_A_START..=JAMO_EXTENDED_A_END
+                | JAMO_EXTENDED_B_START..=JAMO_EXTENDED_B_END
+        )
+}
+
+/// Maps an archaic compatibility jamo (and the compatibility filler) to its
+/// conjoining form, as compatibility decomposition does.
+///
+/// ```rust
+/// use hangeul::archaic::compat_to_conjoining;
+///
+/// assert_eq!(Some('\u{119E}'), compat_to_conjoining(&'ㆍ'));
+/// assert_eq!(Some('\u{1140}'), compat_to_conjoining(&'ㅿ'));
+/// assert_eq!(None, compat_to_conjoining(&'ㄱ'));
+/// ```
+pub fn compat_to_conjoining(c: &char) -> Option<char> {
+    let code = match *c as u32 {
+        0x3164 => 0x1160, // filler
+        0x3165 => 0x1114, // ㅥ
+        0x3166 => 0x1115, // ㅦ
+        0x3167 => 0x11C7, // ㅧ
+        0x3168 => 0x11C8, // ㅨ
+        0x3169 => 0x11CC, // ㅩ
+        0x316A => 0x11CE, // ㅪ
+        0x316B => 0x11D3, // ㅫ
+        0x316C => 0x11D7, // ㅬ
+        0x316D => 0x11D9, // ㅭ
+        0x316E => 0x111C, // ㅮ
+        0x316F => 0x11DD, // ㅯ
+        0x3170 => 0x11DF, // ㅰ
+        0x3171 => 0x111D, // ㅱ
+        0x3172 => 0x111E, // ㅲ
+        0x3173 => 0x1120, // ㅳ
+        0x3174 => 0x1122, // ㅴ
+        0x3175 => 0x1123, // ㅵ
+        0x3176 => 0x1127, // ㅶ
+        0x3177 => 0x1129, // ㅷ
+        0x3178 => 0x112B, // ㅸ
+        0x3179 => 0x112C, // ㅹ
+        0x317A => 0x112D, // ㅺ
+        0x317B => 0x112E, // ㅻ
+        0x317C => 0x112F, // ㅼ
+        0x317D => 0x1132, // ㅽ
+        0x317E => 0x1136, // ㅾ
+        0x317F => 0x1140, // ㅿ
+        0x3180 => 0x1147, // ㆀ
+        0x3181 => 0x114C, // ㆁ
+        0x3182 => 0x11F1, // ㆂ
+        0x3183 => 0x11F2, // ㆃ
+        0x3184 => 0x1157, // ㆄ
+        0x3185 => 0x1158, // ㆅ
+        0x3186 => 0x1159, // ㆆ
+        0x3187 => 0x1184, // ㆇ
+        0x3188 => 0x1185, // ㆈ
+        0x3189 => 0x1188, // ㆉ
+        0x318A => 0x1191, // ㆊ
+        0x318B => 0x1192, // ㆋ
+        0x318C => 0x1194, // ㆌ
+        0x318D => 0x119E, // ㆍ
+        0x318E => 0x11A1, // ㆎ
+        _ => return None,
+    };
+    std::char::from_u32(code)
+}
+
+/// Splits the content into syllable blocks: precomposed syllables, runs of
+/// conjoining jamo that make up one syllable, and any other single char.
+///
+/// ```rust
+/// use hangeul::archaic::syllables;
+///
+/// // 훈민정음: ᄒᆞᆫ (hʌn) can't be precomposed
+/// assert_eq!(vec!["ᄒᆞᆫ", "글"], syllables("ᄒᆞᆫ글"));
+/// ```
+pub fn syllables(content: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut last: Option<Class> = None;
+
+    for c in content.chars() {
+        let class = classify(c);
+        let joins = matches!(
+            (last, class),
+            (Some(Class::L), Some(Class::L))
+                | (Some(Class::L), Some(Class::V))
+                | (Some(Class::L), Some(Class::LV))
+                | (Some(Class::L), Some(Class::LVT))
+                | (Some(Class::V), Some(Class::V))
+                | (Some(Class::V), Some(Class::T))
+                | (Some(Class::LV), Some(Class::V))
+                | (Some(Class::LV), Some(Class::T))
+                | (Some(Class::T), Some(Class::T))
+                | (Some(Class::LVT), Some(Class::T))
+        );
+
+        match (joins, blocks.last_mut()) {
+            (true, Some(block)) => block.push(c),
+            _ => blocks.push(c.to_string()),
+        }
+        last = class;
+    }
+
+    blocks
+}
+
+/// Normalizes old Hangeul the KS X 1026-1 way. Syllables that can be precomposed
+/// are, while the others are spelled out as full conjoining sequences: a
+/// precomposed syllable followed by an archaic jamo is decomposed, and fillers
+/// are added where a syllable lacks its choseong or jungseong.
+///
+/// ```rust
+/// use hangeul::archaic::normalize;
+///
+/// assert_eq!("\u{1100}\u{1161}\u{11EB}", normalize("가\u{11EB}"));
+/// assert_eq!("\u{115F}\u{119E}", normalize("\u{119E}"));
+/// assert_eq!("나랏말ᄊᆞ미", normalize("나랏말ᄊᆞ미"));
+/// assert_eq!("가", normalize("\u{1100}\u{1161}"));
+/// ```
+pub fn normalize(content: &str) -> String {
+    let mut normalized = String::new();
+
+    for block in syllables(&to_nfd(content)) {
+        let jamo: Vec<char> = block.chars().collect();
+        let positions: Vec<Position> = jamo.iter().filter_map(|c| position(*c as u32)).collect();
+        if positions.len() != jamo.len() {
+            normalized.push_str(&block);
+            continue;
+        }
+
+        let part = |position: Position| -> String {
+            jamo.iter()
+                .zip(positions.iter())
+                .filter(|(_, p)| **p == position)
+                .map(|(c, _)| *c)
+                .collect()
+        };
+        let (choseong, jungseong, jongseong) = (
+            part(Position::Choseong),
+            part(Position::Jungseong),
+            part(Position::Jongseong),
+        );
+
+        let mut filled = String::new();
+        match choseong.is_empty() {
+            true => filled.push(to_char(CHOSEONG_FILLER)),
+            false => filled.push_str(&choseong),
+        }
+        match jungseong.is_empty() {
+            true => filled.push(to_char(JUNGSEONG_FILLER)),
+            false => filled.push_str(&jungseong),
+        }
+        filled.push_str(&jongseong);
+
+        let composed = to_nfc(&filled);
+        match composed.chars().count() {
+            1 => normalized.push_str(&composed),
+            _ => normalized.push_str(&filled),
+        }
+    }
+
+    normalized
+}
+
+/// Syllable classes of the Unicode Hangul grapheme cluster rules.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+}
+
+fn classify(c: char) -> Option<Class> {
+    let code = c as u32;
+    match code {
+        SYLLABLE_START..=SYLLABLE_END => match (code - HANGEUL_OFFSET) % JUNGSEONG_COUNT == 0 {
+            true => Some(Class::LV),
+            false => Some(Class::LVT),
+        },
+        _ => match position(code) {
+            Some(Position::Choseong) => Some(Class::L),
+            Some(Position::Jungseong) => Some(Class::V),
+            Some(Position::Jongseong) => Some(Class::T),
+            None => None,
+        },
+    }
+}
+
+fn to_char(code: u32) -> char {
+    std::char::from_u32(code).expect("filler")
+}
@@ -0,0 +1,232 @@
+//! Detection of words typed with the wrong keyboard layout.
+//!
+//! A Latin word is taken as mistyped Hangeul when every key sequence composes into
+//! full syllables ("dkssud" -> "안녕") and the result reads more like Korean than the
+//! original reads like English. The reverse is checked for Hangeul words, so that
+//! "ㅗ디ㅣㅐ" is suggested as "hello".
+use crate::is_hangeul;
+use crate::is_syllable;
+use crate::keyboard::{hangeul_to_qwerty, qwerty_to_hangeul};
+use crate::models::*;
+
+/// Scores at or above this are reported as corrections.
+pub const THRESHOLD: f64 = 0.5;
+
+/// Latin words shorter than this are never taken as Hangeul.
+const MIN_KEYS: usize = 3;
+
+/// The script a word was meant to be typed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intended {
+    Hangeul,
+    Latin,
+}
+
+/// A word that was likely typed in the wrong layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correction {
+    pub original: String,
+    pub corrected: String,
+    pub intended: Intended,
+    /// How plausible the correction is, from 0.0 to 1.0.
+    pub score: f64,
+}
+
+/// Checks a single word, returning the correction if it was likely typed with
+/// the wrong layout.
+///
+/// ```rust
+/// use hangeul::autocorrect::{detect, Intended};
+///
+/// let correction = detect("dkssud").unwrap();
+/// assert_eq!("안녕", correction.corrected);
+/// assert_eq!(Intended::Hangeul, correction.intended);
+///
+/// assert_eq!("hello", detect("ㅗ디ㅣㅐ").unwrap().corrected);
+/// assert_eq!(None, detect("hello"));
+/// assert_eq!(None, detect("안녕"));
+/// ```
+pub fn detect(word: &str) -> Option<Correction> {
+    if word.is_empty() {
+        return None;
+    }
+
+    let correction = if word.chars().all(|c| c.is_ascii_alphabetic()) {
+        // too short to tell apart from English
+        if word.len() < MIN_KEYS {
+            return None;
+        }
+        let corrected = qwerty_to_hangeul(word);
+        // a leftover jamo means the keys don't spell out syllables: dkssudx -> 안녕ㅌ
+        if !corrected.chars().all(|c| is_syllable(c as u32)) {
+            return None;
+        }
+        let score = hangeul_score(&corrected) * (1.0 - 0.7 * latin_score(word));
+        Correction {
+            original: word.to_string(),
+            corrected,
+            intended: Intended::Hangeul,
+            score,
+        }
+    } else if word.chars().all(|c| is_hangeul(c as u32)) {
+        let corrected = hangeul_to_qwerty(word);
+        if !corrected.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let score = latin_score(&corrected) * (1.0 - 0.7 * hangeul_score(word));
+        Correction {
+            original: word.to_string(),
+            corrected,
+            intended: Intended::Latin,
+            score,
+        }
+    } else {
+        return None;
+    };
+
+    match correction.score >= THRESHOLD {
+        true => Some(correction),
+        false => None,
+    }
+}
+
+/// Checks every whitespace separated word of the content. Punctuation around
+/// a word is not part of it.
+///
+/// ```rust
+/// use hangeul::autocorrect::suggest;
+///
+/// let corrections = suggest("dkssudgktpdy, world!");
+/// assert_eq!(1, corrections.len());
+/// assert_eq!("안녕하세요", corrections[0].corrected);
+/// ```
+pub fn suggest(content: &str) -> Vec<Correction> {
+    content
+        .split_whitespace()
+        .filter_map(|word| detect(trim_word(word)))
+        .collect()
+}
+
+/// Replaces every word that was likely typed in the wrong layout.
+///
+/// ```rust
+/// use hangeul::autocorrect::correct;
+///
+/// assert_eq!("안녕, world!", correct("dkssud, world!"));
+/// assert_eq!("hello 세상", correct("ㅗ디ㅣㅐ 세상"));
+/// ```
+pub fn correct(content: &str) -> String {
+    let mut corrected = String::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        corrected.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let word = &rest[..end];
+        let core = trim_word(word);
+
+        match detect(core) {
+            Some(correction) => corrected.push_str(&word.replacen(core, &correction.corrected, 1)),
+            None => corrected.push_str(word),
+        }
+        rest = &rest[end..];
+    }
+    corrected.push_str(rest);
+
+    corrected
+}
+
+fn trim_word(word: &str) -> &str {
+    word.trim_matches(|c: char| c.is_ascii_punctuation())
+}
+
+/// How much the content reads like Korean, from 0.0 to 1.0. Lone jamo and
+/// syllables built from rare jamo lower the score.
+fn hangeul_score(content: &str) -> f64 {
+    let scores: Vec<f64> = content.chars().map(syllable_score).collect();
+
+    match scores.len() {
+        0 => 0.0,
+        n => scores.iter().sum::<f64>() / n as f64,
+    }
+}
+
+fn syllable_score(c: char) -> f64 {
+    if !is_syllable(c as u32) {
+        return 0.0;
+    }
+
+    let jung_penalty: f64 = match Jungseong::from_char(&c) {
+        Some(Jungseong::YAE) | Some(Jungseong::WAE) | Some(Jungseong::WE) => 0.3,
+        Some(Jungseong::YE) | Some(Jungseong::YI) => 0.1,
+        Some(_) => 0.0,
+        None => 1.0,
+    };
+
+    let jong_penalty = match Jongseong::from_char(&c) {
+        Some(Jongseong::GiyeokSiot)
+        | Some(Jongseong::RieulSiot)
+        | Some(Jongseong::RieulTieut)
+        | Some(Jongseong::RieulPieup)
+        | Some(Jongseong::RieulBieup) => 0.5,
+        Some(Jongseong::NieunJieut)
+        | Some(Jongseong::NieunHieuh)
+        | Some(Jongseong::RieulGiyeok)
+        | Some(Jongseong::RieulMieum)
+        | Some(Jongseong::RieulHieuh)
+        | Some(Jongseong::BieupSiot) => 0.3,
+        Some(Jongseong::Kieuk)
+        | Some(Jongseong::Tieut)
+        | Some(Jongseong::Pieup)
+        | Some(Jongseong::Chieut)
+        | Some(Jongseong::Jieut)
+        | Some(Jongseong::Hieuh)
+        | Some(Jongseong::SsangGiyeok) => 0.2,
+        _ => 0.0,
+    };
+
+    (1.0 - jung_penalty - jong_penalty).max(0.0)
+}
+
+/// The most frequent letter pairs of English text.
+const ENGLISH_BIGRAMS: &str = "th he in er an re on at en nd ti es or te of ed is it al ar st \
+    to nt ng se ha as ou io le ve co me de hi ri ro ic ne ea ra ce li ch ll be ma si om ur ca \
+    el ta la ns di fo ho pe ec pr no ct us wo ld";
+
+/// How much the content reads like English, from 0.0 to 1.0, judged by its
+/// share of vowels and of common letter pairs.
+fn latin_score(content: &str) -> f64 {
+    let letters: Vec<char> = content
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if letters.len() < 2 {
+        return 0.0;
+    }
+
+    let vowels = letters.iter().filter(|c| "aeiou".contains(**c)).count();
+    let ratio = vowels as f64 / letters.len() as f64;
+    let vowel_score = if ratio < 0.25 {
+        ratio / 0.25
+    } else if ratio <= 0.6 {
+        1.0
+    } else {
+        (1.0 - ratio) / 0.4
+    };
+
+    let pairs: Vec<String> = letters.windows(2).map(|w| w.iter().collect()).collect();
+    let common = pairs
+        .iter()
+        .filter(|pair| {
+            ENGLISH_BIGRAMS
+                .split_whitespace()
+                .any(|b| b == pair.as_str())
+        })
+        .count();
+    let bigram_score = common as f64 / pairs.len() as f64;
+
+    (vowel_score + bigram_score) / 2.0
+}
@@ -28,3 +28,30 @@ pub const JONGSEONG_START: u32 = 0x11A8;
 pub const JONGSEONG_END: u32 = 0x11C2;
 pub const COMPAT_JONGSEONG_START: u32 = 0x3165;
 pub const COMPAT_JONGSEONG_END: u32 = 0x318E;
+
+// fillers for conjoining jamo sequences missing a choseong or jungseong
+pub const CHOSEONG_FILLER: u32 = 0x115F;
+pub const JUNGSEONG_FILLER: u32 = 0x1160;
+
+// archaic (old Hangeul) jamo
+pub const OLD_CHOSEONG_START: u32 = 0x1113;
+pub const OLD_CHOSEONG_END: u32 = 0x115E;
+pub const OLD_JUNGSEONG_START: u32 = 0x1176;
+pub const OLD_JUNGSEONG_END: u32 = 0x11A7;
+pub const OLD_JONGSEONG_START: u32 = 0x11C3;
+pub const OLD_JONGSEONG_END: u32 = 0x11FF;
+pub const OLD_COMPAT_JAMO_START: u32 = 0x3165;
+pub const OLD_COMPAT_JAMO_END: u32 = 0x318E;
+pub const COMPAT_FILLER: u32 = 0x3164;
+
+// Hangul Jamo Extended-A: archaic choseong
+pub const JAMO_EXTENDED_A_START: u32 = 0xA960;
+pub const JAMO_EXTENDED_A_END: u32 = 0xA97C;
+
+// Hangul Jamo Extended-B: archaic jungseong and jongseong
+pub const JAMO_EXTENDED_B_START: u32 = 0xD7B0;
+pub const JAMO_EXTENDED_B_END: u32 = 0xD7FB;
+pub const EXTENDED_JUNGSEONG_START: u32 = 0xD7B0;
+pub const EXTENDED_JUNGSEONG_END: u32 = 0xD7C6;
+pub const EXTENDED_JONGSEONG_START: u32 = 0xD7CB;
+pub const EXTENDED_JONGSEONG_END: u32 = 0xD7FB;
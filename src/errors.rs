@@ -7,7 +7,12 @@ pub type Result<T> = result::Result<T, HangeulError>;
 pub enum HangeulError {
     JamoNotFound,
     NotASyllable,
+    NotACompound,
     Uncomposable,
+    UnknownFinalSound,
+    OutOfRange,
+    /// The char at the given position can't be read as part of a number.
+    InvalidNumber(usize),
 }
 
 impl fmt::Display for HangeulError {
@@ -15,7 +20,15 @@ impl fmt::Display for HangeulError {
         match self {
             HangeulError::JamoNotFound => write!(f, "HangeulError: Jamo not found"),
             HangeulError::NotASyllable => write!(f, "HangeulError: Not a correct Hangeul syllable"),
+            HangeulError::NotACompound => write!(f, "HangeulError: Not a compound jamo"),
             HangeulError::Uncomposable => write!(f, "HangeulError: Uncomposable"),
+            HangeulError::UnknownFinalSound => {
+                write!(f, "HangeulError: Final sound of the word is unknown")
+            }
+            HangeulError::OutOfRange => write!(f, "HangeulError: Number out of range"),
+            HangeulError::InvalidNumber(position) => {
+                write!(f, "HangeulError: Invalid number at position {}", position)
+            }
         }
     }
 }
@@ -25,7 +38,11 @@ impl error::Error for HangeulError {
         match self {
             HangeulError::JamoNotFound => "HangeulError: Jamo not found",
             HangeulError::NotASyllable => "HangeulError: Not a correct Hangeul syllable",
+            HangeulError::NotACompound => "HangeulError: Not a compound jamo",
             HangeulError::Uncomposable => "HangeulError: Uncomposable",
+            HangeulError::UnknownFinalSound => "HangeulError: Final sound of the word is unknown",
+            HangeulError::OutOfRange => "HangeulError: Number out of range",
+            HangeulError::InvalidNumber(_) => "HangeulError: Invalid number",
         }
     }
 
@@ -0,0 +1,319 @@
+//! A stateful Hangeul input context, similar to the one found in libhangul.
+//!
+//! Jamo are fed one keystroke at a time. The context keeps the syllable that is
+//! still being composed (the preedit) and hands back the text that has been
+//! committed, so that it can sit between a keyboard and a text field.
+//!
+//! Compatibility jamo (`3131`–`318E`) are handled the 2-beolsik way: a consonant
+//! becomes a choseong or a jongseong depending on what surrounds it. Conjoining
+//! jamo (`1100`–`11FF`), as produced by the 3-beolsik layouts, are placed exactly
+//! where they say they belong.
+use crate::compose_char;
+use crate::constants::*;
+use crate::is_compat_jamo;
+use crate::keyboard::Layout;
+use crate::models::*;
+
+/// The result of feeding a single keystroke to an `InputContext`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Output {
+    /// Text that was finished by this keystroke.
+    pub commit: String,
+    /// The syllable (or lone jamo) that is still being composed.
+    pub preedit: Option<char>,
+}
+
+/// A Hangeul input context. Defaults to 2-beolsik (dubeolsik, 두벌식); see
+/// `with_layout` for the 3-beolsik layouts.
+///
+/// ```rust
+/// use hangeul::ime::InputContext;
+///
+/// let mut ic = InputContext::new();
+/// ic.process('ㅎ');
+/// ic.process('ㅏ');
+/// ic.process('ㄴ');
+/// assert_eq!(Some('한'), ic.preedit());
+///
+/// // the trailing ㄴ moves over to the next syllable
+/// let output = ic.process('ㅏ');
+/// assert_eq!("하", output.commit);
+/// assert_eq!(Some('나'), output.preedit);
+/// ```
+#[derive(Debug, Default)]
+pub struct InputContext {
+    layout: Layout,
+    choseong: Option<Choseong>,
+    jungseong: Option<Jungseong>,
+    jongseong: Option<Jongseong>,
+    strokes: Vec<char>,
+}
+
+impl InputContext {
+    pub fn new() -> InputContext {
+        InputContext::default()
+    }
+
+    /// Creates an input context for the given keyboard layout. See `process_key`.
+    ///
+    /// ```rust
+    /// use hangeul::ime::InputContext;
+    /// use hangeul::keyboard::Layout;
+    ///
+    /// let mut ic = InputContext::with_layout(Layout::Sebeolsik390);
+    /// let mut text = String::new();
+    /// for key in "mfskgw".chars() {
+    ///     text.push_str(&ic.process_key(key).commit);
+    /// }
+    /// text.push_str(&ic.flush());
+    /// assert_eq!("한글", text);
+    /// ```
+    pub fn with_layout(layout: Layout) -> InputContext {
+        InputContext {
+            layout,
+            ..InputContext::default()
+        }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Feeds a QWERTY key, mapped through the layout of this context.
+    /// Keys that the layout doesn't map are committed as-is.
+    pub fn process_key(&mut self, key: char) -> Output {
+        let c = self.layout.map_key(key).unwrap_or(key);
+        self.process(c)
+    }
+
+    /// Feeds a single jamo. Any other char commits the current syllable and is
+    /// then committed as-is.
+    pub fn process(&mut self, c: char) -> Output {
+        let mut commit = String::new();
+
+        if !self.push(c, &mut commit) {
+            commit.push_str(&self.flush());
+            commit.push(c);
+        }
+
+        Output {
+            commit,
+            preedit: self.preedit(),
+        }
+    }
+
+    /// Removes the last keystroke of the syllable being composed, jamo by jamo:
+    /// 한 -> 하 -> ㅎ. Returns false if there was nothing to remove.
+    ///
+    /// ```rust
+    /// use hangeul::ime::InputContext;
+    ///
+    /// let mut ic = InputContext::new();
+    /// "ㄷㅏㄹㄱ".chars().for_each(|c| { ic.process(c); });
+    /// assert_eq!(Some('닭'), ic.preedit());
+    ///
+    /// assert!(ic.backspace());
+    /// assert_eq!(Some('달'), ic.preedit());
+    /// ```
+    pub fn backspace(&mut self) -> bool {
+        let mut strokes = std::mem::take(&mut self.strokes);
+
+        match strokes.pop() {
+            Some(_) => {
+                self.replay(strokes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Commits the syllable being composed and resets the context.
+    pub fn flush(&mut self) -> String {
+        let flushed = self.preedit().map(String::from).unwrap_or_default();
+        self.reset();
+        flushed
+    }
+
+    /// Discards the syllable being composed.
+    pub fn reset(&mut self) {
+        *self = InputContext::with_layout(self.layout);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+
+    /// The syllable being composed. Incomplete syllables are shown as a
+    /// single compatibility jamo.
+    pub fn preedit(&self) -> Option<char> {
+        match (&self.choseong, &self.jungseong, &self.jongseong) {
+            (Some(cho), Some(jung), jong) => compose_char(
+                &cho.to_char(),
+                &jung.to_char(),
+                jong.map(|j| j.to_char()).as_ref(),
+            )
+            .ok(),
+            (Some(cho), None, None) => Some(cho.to_char()),
+            (None, Some(jung), None) => Some(jung.to_char()),
+            (None, None, Some(jong)) => Some(jong.to_char()),
+            _ => None,
+        }
+    }
+
+    /// Resets the context and feeds it the given keystrokes again.
+    fn replay(&mut self, strokes: Vec<char>) {
+        self.reset();
+
+        let mut commit = String::new();
+        for c in strokes {
+            self.push(c, &mut commit);
+        }
+        debug_assert!(commit.is_empty());
+    }
+
+    /// Commits the current syllable and starts a new one from the given keystrokes.
+    fn restart(&mut self, strokes: Vec<char>, commit: &mut String) {
+        commit.push_str(&self.flush());
+        self.replay(strokes);
+    }
+
+    /// Whether the last keystroke was a 2-beolsik consonant, which may still
+    /// move over to the next syllable.
+    fn ends_in_consonant_stroke(&self) -> bool {
+        match self.strokes.last() {
+            Some(c) => is_compat_jamo(*c as u32) && Choseong::from_jamo(*c as u32).is_some(),
+            None => false,
+        }
+    }
+
+    fn push(&mut self, c: char, commit: &mut String) -> bool {
+        let code = c as u32;
+
+        if let Some(jung) = Jungseong::from_jamo(code) {
+            return self.push_vowel(c, jung, commit);
+        }
+
+        match code {
+            CHOSEONG_START..=CHOSEONG_END => match Choseong::from_jamo(code) {
+                Some(cho) => self.push_choseong(c, cho, commit),
+                None => false,
+            },
+            JONGSEONG_START..=JONGSEONG_END => match Jongseong::from_jamo(code) {
+                Some(jong) => self.push_jongseong(c, jong, commit),
+                None => false,
+            },
+            _ if is_compat_jamo(code) => self.push_consonant(c, commit),
+            _ => false,
+        }
+    }
+
+    fn push_vowel(&mut self, c: char, jung: Jungseong, commit: &mut String) -> bool {
+        match (&self.choseong, &self.jungseong, &self.jongseong) {
+            (_, None, None) => self.jungseong = Some(jung),
+            (_, Some(prev), None) => match prev.combine(&jung) {
+                Some(compound) => self.jungseong = Some(compound),
+                None => {
+                    self.restart(vec![c], commit);
+                    return true;
+                }
+            },
+            (_, _, Some(_)) if !self.ends_in_consonant_stroke() => {
+                self.restart(vec![c], commit);
+                return true;
+            }
+            (_, _, Some(_)) => {
+                // 도깨비불: the last consonant becomes the lead of the next syllable.
+                let mut strokes = std::mem::take(&mut self.strokes);
+                let moved = strokes.pop().expect("jongseong without a keystroke");
+                self.replay(strokes);
+                self.restart(vec![moved, c], commit);
+                return true;
+            }
+        }
+
+        self.strokes.push(c);
+        true
+    }
+
+    fn push_consonant(&mut self, c: char, commit: &mut String) -> bool {
+        let code = c as u32;
+
+        match (&self.choseong, &self.jungseong, &self.jongseong) {
+            (None, None, None) => {
+                if let Some(cho) = Choseong::from_jamo(code) {
+                    self.choseong = Some(cho);
+                    self.strokes.push(c);
+                    return true;
+                }
+            }
+            (Some(_), Some(_), None) => {
+                if let Some(jong) = Jongseong::from_jamo(code) {
+                    self.jongseong = Some(jong);
+                    self.strokes.push(c);
+                    return true;
+                }
+            }
+            (Some(_), Some(_), Some(prev)) => {
+                let compound = Jongseong::from_jamo(code).and_then(|jong| prev.combine(&jong));
+                if let Some(compound) = compound {
+                    self.jongseong = Some(compound);
+                    self.strokes.push(c);
+                    return true;
+                }
+            }
+            _ => (),
+        }
+
+        match Choseong::from_jamo(code) {
+            Some(_) => self.restart(vec![c], commit),
+            // compound consonants such as ㄳ can't start a syllable
+            None => {
+                commit.push_str(&self.flush());
+                commit.push(c);
+            }
+        }
+        true
+    }
+
+    fn push_choseong(&mut self, c: char, cho: Choseong, commit: &mut String) -> bool {
+        match (&self.choseong, &self.jungseong, &self.jongseong) {
+            (None, None, None) => self.choseong = Some(cho),
+            (Some(prev), None, None) if *prev == cho && prev.double().is_some() => {
+                self.choseong = prev.double()
+            }
+            _ => {
+                self.restart(vec![c], commit);
+                return true;
+            }
+        }
+
+        self.strokes.push(c);
+        true
+    }
+
+    fn push_jongseong(&mut self, c: char, jong: Jongseong, commit: &mut String) -> bool {
+        match (&self.choseong, &self.jungseong, &self.jongseong) {
+            (Some(_), Some(_), None) | (None, None, None) => self.jongseong = Some(jong),
+            (_, _, Some(prev)) => {
+                let compound = match *prev == jong {
+                    true => prev.double(),
+                    false => prev.combine(&jong),
+                };
+                match compound {
+                    Some(compound) => self.jongseong = Some(compound),
+                    None => {
+                        self.restart(vec![c], commit);
+                        return true;
+                    }
+                }
+            }
+            _ => {
+                self.restart(vec![c], commit);
+                return true;
+            }
+        }
+
+        self.strokes.push(c);
+        true
+    }
+}
@@ -0,0 +1,201 @@
+//! Transcription of Hangeul into the International Phonetic Alphabet.
+//!
+//! Words are first pronounced by the rules of `pronunciation`, then every sound
+//! is written as its allophone: plain stops are voiced between voiced sounds
+//! (고기 [ko.ɡi]), ㄹ is a flap before a vowel and [l] elsewhere (다리 [ta.ɾi],
+//! 달 [tal]), ㅅ is [ɕ] before [i] and [j], and stops are unreleased at the end of
+//! a syllable (국 [kuk̚]).
+use crate::models::*;
+use crate::pronunciation::pronounce_word;
+use crate::revised::{split_words, Jamo};
+use std::fmt;
+
+/// A sound of a transcription, or a boundary between syllables or words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phone {
+    Consonant(&'static str),
+    /// The semivowel of a diphthong: j, w, ɥ or ɰ.
+    Glide(&'static str),
+    Vowel(&'static str),
+    SyllableBreak,
+    WordBreak,
+}
+
+impl fmt::Display for Phone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phone::Consonant(symbol) | Phone::Glide(symbol) | Phone::Vowel(symbol) => {
+                write!(f, "{}", symbol)
+            }
+            Phone::SyllableBreak => write!(f, "."),
+            Phone::WordBreak => write!(f, " "),
+        }
+    }
+}
+
+/// The glide and the vowel of a jungseong. ㅢ is [i] after a consonant (희 [hi]),
+/// and ㅖ is [e] after a consonant other than ㄹ (계 [ke]).
+fn jungseong(jung: Jungseong, cho: Choseong) -> (Option<&'static str>, &'static str) {
+    let after_consonant = cho != Choseong::Ieung;
+
+    match jung {
+        Jungseong::A => (None, "a"),
+        Jungseong::AE => (None, "ɛ"),
+        Jungseong::YA => (Some("j"), "a"),
+        Jungseong::YAE => (Some("j"), "ɛ"),
+        Jungseong::EO => (None, "ʌ"),
+        Jungseong::E => (None, "e"),
+        Jungseong::YEO => (Some("j"), "ʌ"),
+        Jungseong::YE if after_consonant && cho != Choseong::Rieul => (None, "e"),
+        Jungseong::YE => (Some("j"), "e"),
+        Jungseong::O => (None, "o"),
+        Jungseong::WA => (Some("w"), "a"),
+        Jungseong::WAE => (Some("w"), "ɛ"),
+        Jungseong::OE => (None, "ø"),
+        Jungseong::YO => (Some("j"), "o"),
+        Jungseong::U => (None, "u"),
+        Jungseong::WEO => (Some("w"), "ʌ"),
+        Jungseong::WE => (Some("w"), "e"),
+        Jungseong::WI => (Some("ɥ"), "i"),
+        Jungseong::YU => (Some("j"), "u"),
+        Jungseong::EU => (None, "ɯ"),
+        Jungseong::YI if after_consonant => (None, "i"),
+        Jungseong::YI => (Some("ɰ"), "i"),
+        Jungseong::I => (None, "i"),
+    }
+}
+
+/// The choseong as pronounced, or `None` for ㅇ. `voiced` is true between voiced
+/// sounds, and `palatal` before [i] and [j].
+fn choseong(cho: Choseong, voiced: bool, palatal: bool) -> Option<&'static str> {
+    let phone = match (cho, voiced, palatal) {
+        (Choseong::Giyeok, false, _) => "k",
+        (Choseong::Giyeok, true, _) => "ɡ",
+        (Choseong::SsangGiyeok, _, _) => "k͈",
+        (Choseong::Kieuk, _, _) => "kʰ",
+        (Choseong::Digeut, false, _) => "t",
+        (Choseong::Digeut, true, _) => "d",
+        (Choseong::SsangDigeut, _, _) => "t͈",
+        (Choseong::Tieut, _, _) => "tʰ",
+        (Choseong::Bieup, false, _) => "p",
+        (Choseong::Bieup, true, _) => "b",
+        (Choseong::SsangBieup, _, _) => "p͈",
+        (Choseong::Pieup, _, _) => "pʰ",
+        (Choseong::Jieut, false, _) => "tɕ",
+        (Choseong::Jieut, true, _) => "dʑ",
+        (Choseong::SsangJieut, _, _) => "t͈ɕ",
+        (Choseong::Chieut, _, _) => "tɕʰ",
+        (Choseong::Siot, _, false) => "s",
+        (Choseong::Siot, _, true) => "ɕ",
+        (Choseong::SsangSiot, _, false) => "s͈",
+        (Choseong::SsangSiot, _, true) => "ɕ͈",
+        (Choseong::Nieun, _, _) => "n",
+        (Choseong::Mieum, _, _) => "m",
+        (Choseong::Rieul, _, _) => "ɾ",
+        (Choseong::Hieuh, false, _) => "h",
+        (Choseong::Hieuh, true, _) => "ɦ",
+        (Choseong::Ieung, _, _) => return None,
+    };
+    Some(phone)
+}
+
+/// The final sounds left by `pronounce_word`.
+fn jongseong(jong: Jongseong) -> &'static str {
+    match jong {
+        Jongseong::Giyeok => "k̚",
+        Jongseong::Nieun => "n",
+        Jongseong::Digeut => "t̚",
+        Jongseong::Rieul => "l",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p̚",
+        _ => "ŋ",
+    }
+}
+
+fn word_phones(word: &[Jamo]) -> Vec<Phone> {
+    let (pronounced, _) = pronounce_word(word, 0);
+    let mut phones = Vec::new();
+    let mut previous: Option<Option<Jongseong>> = None;
+
+    for (cho, jung, jong) in pronounced {
+        let (glide, vowel) = jungseong(jung, cho);
+        let voiced = match previous {
+            None => false,
+            Some(jong) => !matches!(
+                jong,
+                Some(Jongseong::Giyeok | Jongseong::Digeut | Jongseong::Bieup)
+            ),
+        };
+        let palatal = matches!((glide, vowel), (None, "i") | (Some("j" | "ɥ"), _));
+
+        if previous.is_some() {
+            phones.push(Phone::SyllableBreak);
+        }
+        // ㄹ after ㄹ is [l]: 실라 [ɕil.la]
+        let onset = match (previous, cho) {
+            (Some(Some(Jongseong::Rieul)), Choseong::Rieul) => Some("l"),
+            _ => choseong(cho, voiced, palatal),
+        };
+        phones.extend(onset.map(Phone::Consonant));
+        phones.extend(glide.map(Phone::Glide));
+        phones.push(Phone::Vowel(vowel));
+        phones.extend(jong.map(|jong| Phone::Consonant(jongseong(jong))));
+
+        previous = Some(jong);
+    }
+
+    phones
+}
+
+/// The sounds of the Hangeul in the content, with the boundaries between
+/// syllables and words. Everything else is skipped.
+///
+/// ```rust
+/// use hangeul::ipa::{phones, Phone};
+///
+/// assert_eq!(
+///     vec![
+///         Phone::Consonant("k"),
+///         Phone::Vowel("o"),
+///         Phone::SyllableBreak,
+///         Phone::Consonant("ɡ"),
+///         Phone::Vowel("i"),
+///     ],
+///     phones("고기")
+/// );
+/// ```
+pub fn phones(content: &str) -> Vec<Phone> {
+    let mut phones = Vec::new();
+
+    for (word, _) in split_words(content) {
+        if word.is_empty() {
+            continue;
+        }
+        if !phones.is_empty() {
+            phones.push(Phone::WordBreak);
+        }
+        phones.extend(word_phones(&word));
+    }
+
+    phones
+}
+
+/// Transcribes the Hangeul in the content, separating syllables with periods.
+/// Everything else is kept as it is.
+///
+/// ```rust
+/// use hangeul::ipa::transcribe;
+///
+/// assert_eq!("han.ɡu.ɡʌ", transcribe("한국어"));
+/// assert_eq!("kuŋ.mul", transcribe("국물"));
+/// assert_eq!("hak̚.k͈jo", transcribe("학교"));
+/// ```
+pub fn transcribe(content: &str) -> String {
+    split_words(content)
+        .iter()
+        .map(|(word, rest)| {
+            let phones: String = word_phones(word).iter().map(Phone::to_string).collect();
+            phones + rest
+        })
+        .collect()
+}
@@ -0,0 +1,462 @@
+//! Josa (조사), the postposition particles whose form depends on whether the
+//! word before them ends in a consonant: 사과를 but 귤을, 서울로 but 부산으로.
+use crate::errors::*;
+use crate::models::Jongseong;
+use crate::number::{SINO_DIGITS, SINO_GROUPS};
+use std::fmt;
+
+/// A particle that alternates with the final sound of the word it follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Josa {
+    /// 이/가, subject marker
+    IGa,
+    /// 은/는, topic marker
+    EunNeun,
+    /// 을/를, object marker
+    EulReul,
+    /// 과/와, "and", "with"
+    GwaWa,
+    /// 아/야, vocative
+    AYa,
+    /// 이여/여, formal vocative
+    IyeoYeo,
+    /// 이에요/예요, "is"
+    IeyoYeyo,
+    /// (으)로, direction or means. ㄹ takes 로: 서울로, not 서울으로.
+    EuroRo,
+    /// (이)나, "or"
+    INa,
+    /// (이)랑, "and", "with"
+    IRang,
+}
+
+impl Josa {
+    /// The forms taken after a consonant and after a vowel.
+    ///
+    /// ```rust
+    /// use hangeul::josa::Josa;
+    ///
+    /// assert_eq!(("을", "를"), Josa::EulReul.forms());
+    /// ```
+    pub fn forms(&self) -> (&'static str, &'static str) {
+        match self {
+            Josa::IGa => ("이", "가"),
+            Josa::EunNeun => ("은", "는"),
+            Josa::EulReul => ("을", "를"),
+            Josa::GwaWa => ("과", "와"),
+            Josa::AYa => ("아", "야"),
+            Josa::IyeoYeo => ("이여", "여"),
+            Josa::IeyoYeyo => ("이에요", "예요"),
+            Josa::EuroRo => ("으로", "로"),
+            Josa::INa => ("이나", "나"),
+            Josa::IRang => ("이랑", "랑"),
+        }
+    }
+
+    /// The form taken after a syllable with the given jongseong, if any.
+    ///
+    /// ```rust
+    /// use hangeul::josa::Josa;
+    /// use hangeul::models::Jongseong;
+    ///
+    /// assert_eq!("가", Josa::IGa.form_after(None));
+    /// assert_eq!("이", Josa::IGa.form_after(Some(Jongseong::Mieum)));
+    /// assert_eq!("로", Josa::EuroRo.form_after(Some(Jongseong::Rieul)));
+    /// ```
+    pub fn form_after(&self, jongseong: Option<Jongseong>) -> &'static str {
+        let (after_consonant, after_vowel) = self.forms();
+
+        match (self, jongseong) {
+            (_, None) | (Josa::EuroRo, Some(Jongseong::Rieul)) => after_vowel,
+            (_, Some(_)) => after_consonant,
+        }
+    }
+
+    /// The way the particle is written when the form can't be told in advance,
+    /// as in "{name}이(가)".
+    ///
+    /// ```rust
+    /// use hangeul::josa::Josa;
+    ///
+    /// assert_eq!("이(가)", Josa::IGa.pattern());
+    /// assert_eq!("(으)로", Josa::EuroRo.pattern());
+    /// ```
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            Josa::IGa => "이(가)",
+            Josa::EunNeun => "은(는)",
+            Josa::EulReul => "을(를)",
+            Josa::GwaWa => "과(와)",
+            Josa::AYa => "아(야)",
+            Josa::IyeoYeo => "(이)여",
+            Josa::IeyoYeyo => "이에요(예요)",
+            Josa::EuroRo => "(으)로",
+            Josa::INa => "(이)나",
+            Josa::IRang => "(이)랑",
+        }
+    }
+}
+
+/// Every way a particle pattern is written, longest first where they overlap.
+const PATTERNS: [(&str, Josa); 17] = [
+    ("이에요(예요)", Josa::IeyoYeyo),
+    ("예요(이에요)", Josa::IeyoYeyo),
+    ("(이)에요", Josa::IeyoYeyo),
+    ("이(가)", Josa::IGa),
+    ("가(이)", Josa::IGa),
+    ("은(는)", Josa::EunNeun),
+    ("는(은)", Josa::EunNeun),
+    ("을(를)", Josa::EulReul),
+    ("를(을)", Josa::EulReul),
+    ("과(와)", Josa::GwaWa),
+    ("와(과)", Josa::GwaWa),
+    ("아(야)", Josa::AYa),
+    ("야(아)", Josa::AYa),
+    ("(이)여", Josa::IyeoYeo),
+    ("(으)로", Josa::EuroRo),
+    ("(이)나", Josa::INa),
+    ("(이)랑", Josa::IRang),
+];
+
+/// The jongseong of the word as it is read aloud, if it ends in one.
+///
+/// Words that don't end in Hangeul are read the way Korean speakers would:
+/// digits in Sino-Korean (3 -> 삼, 10 -> 십), capital letters by their names
+/// (SQL -> 에스큐엘), other Latin words by rough pronunciation (Apple -> 애플) and
+/// a few common symbols (% -> 퍼센트). Trailing quotes are skipped, and so are
+/// trailing brackets along with what they enclose: 한글(韓㐎) is read as 한글.
+///
+/// ```rust
+/// use hangeul::josa::final_jongseong;
+/// use hangeul::models::Jongseong;
+///
+/// assert_eq!(Ok(Some(Jongseong::Rieul)), final_jongseong("서울"));
+/// assert_eq!(Ok(None), final_jongseong("부산시"));
+/// assert_eq!(Ok(Some(Jongseong::Mieum)), final_jongseong("3"));
+/// assert_eq!(Ok(Some(Jongseong::Rieul)), final_jongseong("SQL"));
+/// assert_eq!(Ok(Some(Jongseong::Rieul)), final_jongseong("한글(韓㐎)"));
+/// ```
+pub fn final_jongseong(word: &str) -> Result<Option<Jongseong>> {
+    let word = trim_trailing(word);
+
+    match word.chars().last() {
+        Some(c) if crate::is_syllable(c as u32) => Ok(Jongseong::from_char(&c)),
+        Some(c) if c.is_ascii_digit() => Ok(number_jongseong(word)),
+        Some(c) if c.is_ascii_uppercase() => Ok(reading_jongseong(letter_name(c))),
+        Some(c) if c.is_ascii_lowercase() => Ok(english_jongseong(word)),
+        Some(c) => match symbol_name(c) {
+            Some(name) => Ok(reading_jongseong(name)),
+            None => Err(HangeulError::UnknownFinalSound),
+        },
+        None => Err(HangeulError::UnknownFinalSound),
+    }
+}
+
+fn is_closing_quote(c: char) -> bool {
+    matches!(c, '"' | '\'' | '”' | '’' | '»' | '」' | '』' | '〉' | '》')
+}
+
+fn opening_bracket(c: char) -> Option<char> {
+    match c {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        '）' => Some('（'),
+        _ => None,
+    }
+}
+
+/// Strips trailing whitespace, quotes and bracketed groups. A group that makes up
+/// the whole word is unwrapped instead.
+fn trim_trailing(word: &str) -> &str {
+    let mut word = word.trim_end();
+
+    while let Some(last) = word.chars().last() {
+        let without_last = &word[..word.len() - last.len_utf8()];
+
+        if is_closing_quote(last) {
+            word = without_last.trim_end();
+            continue;
+        }
+
+        let open = match opening_bracket(last) {
+            Some(open) => open,
+            None => break,
+        };
+
+        let mut depth = 0;
+        let mut start = None;
+        for (i, c) in without_last.char_indices().rev() {
+            if c == last {
+                depth += 1;
+            } else if c == open && depth > 0 {
+                depth -= 1;
+            } else if c == open {
+                start = Some(i);
+                break;
+            }
+        }
+
+        word = match start {
+            Some(0) => &without_last[open.len_utf8()..],
+            Some(i) => &without_last[..i],
+            // unbalanced, so only the bracket itself goes
+            None => without_last,
+        }
+        .trim_end();
+    }
+
+    word
+}
+
+/// The jongseong of the last syllable of a Hangeul reading.
+fn reading_jongseong(reading: &str) -> Option<Jongseong> {
+    reading
+        .chars()
+        .last()
+        .and_then(|c| Jongseong::from_char(&c))
+}
+
+/// Reads the trailing number of the word in Sino-Korean. Only the end of the
+/// reading matters: 1,000 is 천, 20000 is 만 and 3.14 ends in 사.
+fn number_jongseong(word: &str) -> Option<Jongseong> {
+    let start = word
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .last()
+        .map_or(0, |(i, _)| i);
+    let number = &word[start..];
+
+    let digits: Vec<u32> = match number.rfind('.') {
+        Some(point) => &number[point + 1..],
+        None => number,
+    }
+    .chars()
+    .filter_map(|c| c.to_digit(10))
+    .collect();
+
+    let zeros = digits.iter().rev().take_while(|d| **d == 0).count();
+    let reading = if zeros == digits.len() {
+        SINO_DIGITS[0]
+    } else {
+        match (zeros % 4, zeros / 4) {
+            (0, 0) => SINO_DIGITS[digits[digits.len() - 1] as usize],
+            (1, _) => "십",
+            (2, _) => "백",
+            (3, _) => "천",
+            (_, group) => SINO_GROUPS.get(group).unwrap_or(&"해"),
+        }
+    };
+
+    reading_jongseong(reading)
+}
+
+fn letter_name(c: char) -> &'static str {
+    match c.to_ascii_uppercase() {
+        'A' => "에이",
+        'B' => "비",
+        'C' => "씨",
+        'D' => "디",
+        'E' => "이",
+        'F' => "에프",
+        'G' => "지",
+        'H' => "에이치",
+        'I' => "아이",
+        'J' => "제이",
+        'K' => "케이",
+        'L' => "엘",
+        'M' => "엠",
+        'N' => "엔",
+        'O' => "오",
+        'P' => "피",
+        'Q' => "큐",
+        'R' => "알",
+        'S' => "에스",
+        'T' => "티",
+        'U' => "유",
+        'V' => "브이",
+        'W' => "더블유",
+        'X' => "엑스",
+        'Y' => "와이",
+        _ => "제트",
+    }
+}
+
+fn symbol_name(c: char) -> Option<&'static str> {
+    match c {
+        '%' => Some("퍼센트"),
+        '+' => Some("플러스"),
+        '#' => Some("샵"),
+        '@' => Some("앳"),
+        '&' => Some("앤드"),
+        '$' => Some("달러"),
+        '₩' => Some("원"),
+        '¥' => Some("엔"),
+        '€' => Some("유로"),
+        '£' => Some("파운드"),
+        '°' | '℃' | '℉' => Some("도"),
+        _ => None,
+    }
+}
+
+/// Guesses how an English word ends when written in Hangeul, following the usual
+/// loanword spellings: Apple -> 애플, Google -> 구글, internet -> 인터넷, but
+/// computer -> 컴퓨터 and test -> 테스트.
+fn english_jongseong(word: &str) -> Option<Jongseong> {
+    let letters: Vec<u8> = word
+        .bytes()
+        .rev()
+        .take_while(|b| b.is_ascii_alphabetic())
+        .map(|b| b.to_ascii_lowercase())
+        .collect();
+    // letters are reversed: 0 is the last letter
+    let at = |i: usize| letters.get(i).copied();
+    let is_vowel = |i: usize| matches!(at(i), Some(b'a' | b'e' | b'i' | b'o' | b'u'));
+
+    match (at(0), at(1)) {
+        (Some(b'g'), Some(b'n')) => Some(Jongseong::Ieung),
+        (Some(b'm'), _) | (Some(b'e'), Some(b'm')) => Some(Jongseong::Mieum),
+        (Some(b'n'), _) | (Some(b'e'), Some(b'n')) => Some(Jongseong::Nieun),
+        (Some(b'l'), _) | (Some(b'e'), Some(b'l')) => Some(Jongseong::Rieul),
+        (Some(b'k'), Some(b'c')) => Some(Jongseong::Giyeok),
+        (Some(b'k' | b'c'), _) if is_vowel(1) => Some(Jongseong::Giyeok),
+        (Some(b'p'), _) if is_vowel(1) => Some(Jongseong::Bieup),
+        (Some(b'p'), Some(b'p')) if is_vowel(2) => Some(Jongseong::Bieup),
+        (Some(b't'), _) if is_vowel(1) && !is_vowel(2) => Some(Jongseong::Siot),
+        _ => None,
+    }
+}
+
+/// Picks the form of the particle that follows the word.
+///
+/// ```rust
+/// use hangeul::josa::{pick, Josa};
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok("가"), pick("피카츄", Josa::IGa));
+/// assert_eq!(Ok("으로"), pick("부엌", Josa::EuroRo));
+/// assert_eq!(Err(HangeulError::UnknownFinalSound), pick("", Josa::IGa));
+/// ```
+pub fn pick(word: &str, josa: Josa) -> Result<&'static str> {
+    Ok(josa.form_after(final_jongseong(word)?))
+}
+
+/// Attaches the right form of the particle to the word.
+///
+/// ```rust
+/// use hangeul::josa::{attach, Josa};
+///
+/// assert_eq!(Ok("이상해꽃이".to_string()), attach("이상해꽃", Josa::IGa));
+/// assert_eq!(Ok("서울로".to_string()), attach("서울", Josa::EuroRo));
+/// ```
+pub fn attach(word: &str, josa: Josa) -> Result<String> {
+    let form = pick(word, josa)?;
+    Ok(format!("{}{}", word, form))
+}
+
+/// A value followed by a particle, formatted with the form the value calls for.
+/// Values that can't be read are followed by the pattern instead, as in 韓이(가).
+/// See `JosaExt`.
+#[derive(Debug, Clone, Copy)]
+pub struct WithJosa<'a, T: ?Sized> {
+    value: &'a T,
+    josa: Josa,
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for WithJosa<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.value.to_string();
+        let form = pick(&value, self.josa).unwrap_or_else(|_| self.josa.pattern());
+        write!(f, "{}{}", value, form)
+    }
+}
+
+/// Attaches particles to anything that can be displayed, for use with `format!`
+/// and friends.
+///
+/// ```rust
+/// use hangeul::josa::{Josa, JosaExt};
+///
+/// let name = "피카츄";
+/// assert_eq!("피카츄가 나타났다", format!("{} 나타났다", name.josa(Josa::IGa)));
+/// assert_eq!("3을 더하면", format!("{} 더하면", 3.josa(Josa::EulReul)));
+/// ```
+pub trait JosaExt: fmt::Display {
+    fn josa(&self, josa: Josa) -> WithJosa<'_, Self> {
+        WithJosa { value: self, josa }
+    }
+}
+
+impl<T: fmt::Display + ?Sized> JosaExt for T {}
+
+/// Fills the placeholders of a template, and resolves the particle patterns that
+/// follow them against the substituted values: 이(가), 을(를), 은(는), 와(과),
+/// (으)로, (이)나 and so on. `{{` and `}}` are literal braces, and placeholders
+/// without a matching argument are left as they are.
+///
+/// ```rust
+/// use hangeul::josa::format;
+///
+/// let text = format("{name}이(가) {item}을(를) 획득했습니다", &[("name", &"피카츄"), ("item", &"몬스터볼")]);
+/// assert_eq!("피카츄가 몬스터볼을 획득했습니다", text);
+///
+/// assert_eq!("서울로 가는 길", format("{city}(으)로 가는 길", &[("city", &"서울")]));
+/// assert_eq!("10개를 샀다", format("{count}개를 샀다", &[("count", &10)]));
+/// ```
+pub fn format(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut formatted = String::new();
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        formatted.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            formatted.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+
+        let end = match rest.find('}') {
+            Some(end) if rest.starts_with('{') => end,
+            _ => {
+                formatted.push_str(&rest[..1]);
+                rest = &rest[1..];
+                continue;
+            }
+        };
+
+        let key = &rest[1..end];
+        let placeholder = &rest[..=end];
+        rest = &rest[end + 1..];
+
+        let value = match args.iter().find(|(name, _)| *name == key) {
+            Some((_, value)) => value.to_string(),
+            None => {
+                formatted.push_str(placeholder);
+                continue;
+            }
+        };
+
+        match PATTERNS
+            .iter()
+            .find(|(pattern, _)| rest.starts_with(pattern))
+        {
+            Some((pattern, josa)) => {
+                formatted.push_str(
+                    &WithJosa {
+                        value: &value,
+                        josa: *josa,
+                    }
+                    .to_string(),
+                );
+                rest = &rest[pattern.len()..];
+            }
+            None => formatted.push_str(&value),
+        }
+    }
+    formatted.push_str(rest);
+
+    formatted
+}
@@ -0,0 +1,362 @@
+//! Korean keyboard layouts, mapping QWERTY keys to jamo.
+//!
+//! 2-beolsik keys map to compatibility jamo, since a consonant key can be
+//! either a choseong or a jongseong. 3-beolsik keys map to conjoining jamo
+//! (`1100`–`11FF`), which already carry their position in the syllable.
+use crate::decompose_char;
+use crate::ime::InputContext;
+
+/// A Korean keyboard layout laid over a US QWERTY keyboard.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// 2-beolsik (두벌식), the KS X 5002 standard layout.
+    #[default]
+    Dubeolsik,
+    /// 3-beolsik 390 (세벌식 390).
+    Sebeolsik390,
+    /// 3-beolsik Final (세벌식 최종).
+    SebeolsikFinal,
+    /// 3-beolsik Noshift (세벌식 순아래). Shift is never needed; the remaining
+    /// jongseong are on the punctuation keys and compounds are typed in two strokes.
+    SebeolsikNoshift,
+}
+
+impl Layout {
+    /// Maps a QWERTY key to the char it produces in this layout, which may also be
+    /// a digit or a symbol. Returns `None` for keys that the layout leaves alone.
+    ///
+    /// ```rust
+    /// use hangeul::keyboard::Layout;
+    ///
+    /// assert_eq!(Some('ㅎ'), Layout::Dubeolsik.map_key('g'));
+    /// assert_eq!(Some('ㄲ'), Layout::Dubeolsik.map_key('R'));
+    /// assert_eq!(Some('\u{1112}'), Layout::Sebeolsik390.map_key('m')); // choseong ㅎ
+    /// assert_eq!(Some('\u{11C2}'), Layout::Sebeolsik390.map_key('1')); // jongseong ㅎ
+    /// assert_eq!(None, Layout::Dubeolsik.map_key('1'));
+    /// ```
+    pub fn map_key(&self, key: char) -> Option<char> {
+        match self {
+            Layout::Dubeolsik => dubeolsik(key),
+            Layout::Sebeolsik390 => sebeolsik_390(key),
+            Layout::SebeolsikFinal => sebeolsik_final(key),
+            Layout::SebeolsikNoshift => sebeolsik_noshift(key),
+        }
+    }
+}
+
+fn dubeolsik(key: char) -> Option<char> {
+    let c = match key {
+        'Q' => 'ㅃ',
+        'W' => 'ㅉ',
+        'E' => 'ㄸ',
+        'R' => 'ㄲ',
+        'T' => 'ㅆ',
+        'O' => 'ㅒ',
+        'P' => 'ㅖ',
+        'q' => 'ㅂ',
+        'w' => 'ㅈ',
+        'e' => 'ㄷ',
+        'r' => 'ㄱ',
+        't' => 'ㅅ',
+        'y' | 'Y' => 'ㅛ',
+        'u' | 'U' => 'ㅕ',
+        'i' | 'I' => 'ㅑ',
+        'o' => 'ㅐ',
+        'p' => 'ㅔ',
+        'a' | 'A' => 'ㅁ',
+        's' | 'S' => 'ㄴ',
+        'd' | 'D' => 'ㅇ',
+        'f' | 'F' => 'ㄹ',
+        'g' | 'G' => 'ㅎ',
+        'h' | 'H' => 'ㅗ',
+        'j' | 'J' => 'ㅓ',
+        'k' | 'K' => 'ㅏ',
+        'l' | 'L' => 'ㅣ',
+        'z' | 'Z' => 'ㅋ',
+        'x' | 'X' => 'ㅌ',
+        'c' | 'C' => 'ㅊ',
+        'v' | 'V' => 'ㅍ',
+        'b' | 'B' => 'ㅠ',
+        'n' | 'N' => 'ㅜ',
+        'm' | 'M' => 'ㅡ',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// The unshifted keys, shared by every 3-beolsik layout.
+fn sebeolsik(key: char) -> Option<char> {
+    let c = match key {
+        '1' => '\u{11C2}',  // ㅎ
+        '2' => '\u{11BB}',  // ㅆ
+        '3' => '\u{11B8}',  // ㅂ
+        '4' => '\u{116D}',  // ㅛ
+        '5' => '\u{1172}',  // ㅠ
+        '6' => '\u{1163}',  // ㅑ
+        '7' => '\u{1168}',  // ㅖ
+        '8' => '\u{1174}',  // ㅢ
+        '9' => '\u{116E}',  // ㅜ
+        '0' => '\u{110F}',  // ㅋ
+        'q' => '\u{11BA}',  // ㅅ
+        'w' => '\u{11AF}',  // ㄹ
+        'e' => '\u{1167}',  // ㅕ
+        'r' => '\u{1162}',  // ㅐ
+        't' => '\u{1165}',  // ㅓ
+        'y' => '\u{1105}',  // ㄹ
+        'u' => '\u{1103}',  // ㄷ
+        'i' => '\u{1106}',  // ㅁ
+        'o' => '\u{110E}',  // ㅊ
+        'p' => '\u{1111}',  // ㅍ
+        'a' => '\u{11BC}',  // ㅇ
+        's' => '\u{11AB}',  // ㄴ
+        'd' => '\u{1175}',  // ㅣ
+        'f' => '\u{1161}',  // ㅏ
+        'g' => '\u{1173}',  // ㅡ
+        'h' => '\u{1102}',  // ㄴ
+        'j' => '\u{110B}',  // ㅇ
+        'k' => '\u{1100}',  // ㄱ
+        'l' => '\u{110C}',  // ㅈ
+        ';' => '\u{1107}',  // ㅂ
+        '\'' => '\u{1110}', // ㅌ
+        'z' => '\u{11B7}',  // ㅁ
+        'x' => '\u{11A8}',  // ㄱ
+        'c' => '\u{1166}',  // ㅔ
+        'v' => '\u{1169}',  // ㅗ
+        'b' => '\u{116E}',  // ㅜ
+        'n' => '\u{1109}',  // ㅅ
+        'm' => '\u{1112}',  // ㅎ
+        '/' => '\u{1169}',  // ㅗ
+        _ => return None,
+    };
+    Some(c)
+}
+
+fn sebeolsik_390(key: char) -> Option<char> {
+    let c = match key {
+        '!' => '\u{11BD}', // ㅈ
+        'Q' => '\u{11C1}', // ㅍ
+        'W' => '\u{11C0}', // ㅌ
+        'E' => '\u{11BF}', // ㅋ
+        'R' => '\u{1164}', // ㅒ
+        'T' => ';',
+        'Y' => '<',
+        'U' => '7',
+        'I' => '8',
+        'O' => '9',
+        'P' => '>',
+        'A' => '\u{11AE}', // ㄷ
+        'S' => '\u{11AD}', // ㄶ
+        'D' => '\u{11B0}', // ㄺ
+        'F' => '\u{11A9}', // ㄲ
+        'G' => '/',
+        'H' => '\'',
+        'J' => '4',
+        'K' => '5',
+        'L' => '6',
+        'Z' => '\u{11BE}', // ㅊ
+        'X' => '\u{11B9}', // ㅄ
+        'C' => '\u{11B1}', // ㄻ
+        'V' => '\u{11B6}', // ㅀ
+        'B' => '!',
+        'N' => '0',
+        'M' => '1',
+        '<' => '2',
+        '>' => '3',
+        _ => return sebeolsik(key),
+    };
+    Some(c)
+}
+
+fn sebeolsik_final(key: char) -> Option<char> {
+    let c = match key {
+        '!' => '\u{11A9}', // ㄲ
+        '@' => '\u{11B0}', // ㄺ
+        '#' => '\u{11BD}', // ㅈ
+        '$' => '\u{11B5}', // ㄿ
+        '%' => '\u{11B4}', // ㄾ
+        '^' => '=',
+        '&' => '“',
+        '*' => '”',
+        '(' => '\'',
+        ')' => '~',
+        'Q' => '\u{11C1}', // ㅍ
+        'W' => '\u{11C0}', // ㅌ
+        'E' => '\u{11AC}', // ㄵ
+        'R' => '\u{11B6}', // ㅀ
+        'T' => '\u{11B3}', // ㄽ
+        'Y' => '5',
+        'U' => '6',
+        'I' => '7',
+        'O' => '8',
+        'P' => '9',
+        'A' => '\u{11AE}', // ㄷ
+        'S' => '\u{11AD}', // ㄶ
+        'D' => '\u{11B2}', // ㄼ
+        'F' => '\u{11B1}', // ㄻ
+        'G' => '\u{1164}', // ㅒ
+        'H' => '0',
+        'J' => '1',
+        'K' => '2',
+        'L' => '3',
+        ':' => '4',
+        'Z' => '\u{11BE}', // ㅊ
+        'X' => '\u{11B9}', // ㅄ
+        'C' => '\u{11BF}', // ㅋ
+        'V' => '\u{11AA}', // ㄳ
+        'B' => '?',
+        'N' => '-',
+        'M' => '"',
+        '<' => ',',
+        '>' => '.',
+        '?' => '!',
+        _ => return sebeolsik(key),
+    };
+    Some(c)
+}
+
+fn sebeolsik_noshift(key: char) -> Option<char> {
+    let c = match key {
+        '-' => '\u{11BD}',  // ㅈ
+        '=' => '\u{11BE}',  // ㅊ
+        '[' => '\u{11C1}',  // ㅍ
+        ']' => '\u{11C0}',  // ㅌ
+        '\\' => '\u{11BF}', // ㅋ
+        '`' => '\u{11AE}',  // ㄷ
+        _ => return sebeolsik(key.to_ascii_lowercase()),
+    };
+    Some(c)
+}
+
+/// Converts keys typed on a QWERTY keyboard into the Hangeul they would have
+/// produced in 2-beolsik, as when text was typed with the IME left in English mode.
+/// Keys that don't map to a jamo are left alone.
+///
+/// ```rust
+/// use hangeul::keyboard::qwerty_to_hangeul;
+///
+/// assert_eq!("한글", qwerty_to_hangeul("gksrmf"));
+/// assert_eq!("안녕하세요!", qwerty_to_hangeul("dkssudgktpdy!"));
+/// ```
+pub fn qwerty_to_hangeul(keys: &str) -> String {
+    let mut ic = InputContext::with_layout(Layout::Dubeolsik);
+    let mut converted = String::new();
+
+    for key in keys.chars() {
+        converted.push_str(&ic.process_key(key).commit);
+    }
+    converted.push_str(&ic.flush());
+
+    converted
+}
+
+/// Converts Hangeul into the keys that type it in 2-beolsik, compound jamo
+/// being split into their strokes (ㄺ -> `fr`, ㅘ -> `hk`). Shifted keys are
+/// given in upper case. Anything else is left alone.
+///
+/// ```rust
+/// use hangeul::keyboard::hangeul_to_qwerty;
+///
+/// assert_eq!("gksrmf", hangeul_to_qwerty("한글"));
+/// assert_eq!("ekfr rhk Qkd", hangeul_to_qwerty("닭 과 빵"));
+/// ```
+pub fn hangeul_to_qwerty(content: &str) -> String {
+    let mut keys = String::new();
+
+    for c in content.chars() {
+        match decompose_char(&c) {
+            Ok((cho, jung, jong)) => {
+                let jamo = [Some(cho), Some(jung), jong];
+                jamo.iter()
+                    .flatten()
+                    .filter_map(|j| jamo_keys(*j))
+                    .for_each(|k| keys.push_str(k));
+            }
+            Err(_) => match jamo_keys(c) {
+                Some(k) => keys.push_str(k),
+                None => keys.push(c),
+            },
+        }
+    }
+
+    keys
+}
+
+/// Counts the key presses (타수) needed to type the content in 2-beolsik,
+/// each Shift counting as one more press.
+///
+/// ```rust
+/// use hangeul::keyboard::count_keystrokes;
+///
+/// assert_eq!(6, count_keystrokes("한글"));
+/// assert_eq!(4, count_keystrokes("빵")); // Shift, q, k, d
+/// assert_eq!(5, count_keystrokes("Hi!"));
+/// ```
+pub fn count_keystrokes(content: &str) -> usize {
+    hangeul_to_qwerty(content)
+        .chars()
+        .map(|k| if is_shifted(k) { 2 } else { 1 })
+        .sum()
+}
+
+fn is_shifted(key: char) -> bool {
+    key.is_ascii_uppercase() || "~!@#$%^&*()_+{}|:\"<>?".contains(key)
+}
+
+/// The 2-beolsik keys for a compatibility jamo, compounds included.
+fn jamo_keys(jamo: char) -> Option<&'static str> {
+    let keys = match jamo {
+        'ㄱ' => "r",
+        'ㄲ' => "R",
+        'ㄳ' => "rt",
+        'ㄴ' => "s",
+        'ㄵ' => "sw",
+        'ㄶ' => "sg",
+        'ㄷ' => "e",
+        'ㄸ' => "E",
+        'ㄹ' => "f",
+        'ㄺ' => "fr",
+        'ㄻ' => "fa",
+        'ㄼ' => "fq",
+        'ㄽ' => "ft",
+        'ㄾ' => "fx",
+        'ㄿ' => "fv",
+        'ㅀ' => "fg",
+        'ㅁ' => "a",
+        'ㅂ' => "q",
+        'ㅃ' => "Q",
+        'ㅄ' => "qt",
+        'ㅅ' => "t",
+        'ㅆ' => "T",
+        'ㅇ' => "d",
+        'ㅈ' => "w",
+        'ㅉ' => "W",
+        'ㅊ' => "c",
+        'ㅋ' => "z",
+        'ㅌ' => "x",
+        'ㅍ' => "v",
+        'ㅎ' => "g",
+        'ㅏ' => "k",
+        'ㅐ' => "o",
+        'ㅑ' => "i",
+        'ㅒ' => "O",
+        'ㅓ' => "j",
+        'ㅔ' => "p",
+        'ㅕ' => "u",
+        'ㅖ' => "P",
+        'ㅗ' => "h",
+        'ㅘ' => "hk",
+        'ㅙ' => "ho",
+        'ㅚ' => "hl",
+        'ㅛ' => "y",
+        'ㅜ' => "n",
+        'ㅝ' => "nj",
+        'ㅞ' => "np",
+        'ㅟ' => "nl",
+        'ㅠ' => "b",
+        'ㅡ' => "m",
+        'ㅢ' => "ml",
+        'ㅣ' => "l",
+        _ => return None,
+    };
+    Some(keys)
+}
@@ -0,0 +1,186 @@
+//! Transliteration into Cyrillic by the Kontsevich system (система Концевича),
+//! the standard for Korean names in Russian.
+//!
+//! Words are written as they are pronounced. ㄱ, ㄷ, ㅂ and ㅈ are voiced after
+//! vowels and ㄴ, ㄹ, ㅁ and ㅇ (Пусан but Тэгу), ㄹ is р before a vowel and ль
+//! after one (Ульсан), and both ㅓ and ㅗ are о.
+use crate::models::*;
+use crate::revised::{capitalize, pronounce, split_words, to_jamo, Jamo};
+
+/// How text is transliterated. The default follows the system, in lowercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Capitalizes every word, as is done for proper nouns: Пусан.
+    pub capitalize: bool,
+    /// Keeps the established Russian spelling of a few names that predate the
+    /// system: Сеул rather than Соуль, and Ли for the surname 이 when it is the
+    /// first word, as in a personal name.
+    pub traditional: bool,
+}
+
+/// Established spellings, used for whole words only.
+const TRADITIONAL: [(&str, &str); 2] = [("서울", "сеул"), ("평양", "пхеньян")];
+
+/// Established spellings of surnames, used for the first word only: 이 is also
+/// a common word on its own.
+const SURNAMES: [(&str, &str); 2] = [("이", "ли"), ("리", "ли")];
+
+/// Plain consonants are written voiceless at the start of a word or after ㄱ, ㄷ
+/// and ㅂ, and voiced elsewhere.
+fn choseong(cho: Choseong, voiced: bool) -> &'static str {
+    match (cho, voiced) {
+        (Choseong::Giyeok, false) => "к",
+        (Choseong::Giyeok, true) => "г",
+        (Choseong::Digeut, false) => "т",
+        (Choseong::Digeut, true) => "д",
+        (Choseong::Bieup, false) => "п",
+        (Choseong::Bieup, true) => "б",
+        (Choseong::Jieut, false) => "ч",
+        (Choseong::Jieut, true) => "дж",
+        (Choseong::SsangGiyeok, _) => "кк",
+        (Choseong::Nieun, _) => "н",
+        (Choseong::SsangDigeut, _) => "тт",
+        (Choseong::Rieul, _) => "р",
+        (Choseong::Mieum, _) => "м",
+        (Choseong::SsangBieup, _) => "пп",
+        (Choseong::Siot, _) => "с",
+        (Choseong::SsangSiot, _) => "сс",
+        (Choseong::Ieung, _) => "",
+        (Choseong::SsangJieut, _) => "чч",
+        (Choseong::Chieut, _) => "чх",
+        (Choseong::Kieuk, _) => "кх",
+        (Choseong::Tieut, _) => "тх",
+        (Choseong::Pieup, _) => "пх",
+        (Choseong::Hieuh, _) => "х",
+    }
+}
+
+/// Vowels are written differently when they start a syllable: 에 is э but 세 is
+/// се, and 의 is ый but 희 is хи.
+fn jungseong(jung: Jungseong, initial: bool) -> &'static str {
+    match (jung, initial) {
+        (Jungseong::A, _) => "а",
+        (Jungseong::AE, _) => "э",
+        (Jungseong::YA, _) => "я",
+        (Jungseong::YAE, _) => "йя",
+        (Jungseong::EO, _) => "о",
+        (Jungseong::E, true) => "э",
+        (Jungseong::E, false) => "е",
+        (Jungseong::YEO, _) => "ё",
+        (Jungseong::YE, true) => "йе",
+        (Jungseong::YE, false) => "е",
+        (Jungseong::O, _) => "о",
+        (Jungseong::WA, _) => "ва",
+        (Jungseong::WAE, _) => "вэ",
+        (Jungseong::OE, _) => "ве",
+        (Jungseong::YO, _) => "ё",
+        (Jungseong::U, _) => "у",
+        (Jungseong::WEO, _) => "во",
+        (Jungseong::WE, _) => "ве",
+        (Jungseong::WI, _) => "ви",
+        (Jungseong::YU, _) => "ю",
+        (Jungseong::EU, _) => "ы",
+        (Jungseong::YI, true) => "ый",
+        (Jungseong::YI, false) => "и",
+        (Jungseong::I, _) => "и",
+    }
+}
+
+/// The final sounds left by `pronounce`. ㄹ is ль, or л before another ㄹ.
+fn jongseong(jong: Jongseong, next: Option<Choseong>) -> &'static str {
+    match (jong, next) {
+        (Jongseong::Giyeok, _) => "к",
+        (Jongseong::Nieun, _) => "н",
+        (Jongseong::Digeut, _) => "т",
+        (Jongseong::Rieul, Some(Choseong::Rieul)) => "л",
+        (Jongseong::Rieul, _) => "ль",
+        (Jongseong::Mieum, _) => "м",
+        (Jongseong::Bieup, _) => "п",
+        _ => "н",
+    }
+}
+
+fn transliterate_word(word: &[Jamo]) -> String {
+    let pronounced = pronounce(word);
+    let mut transliterated = String::new();
+    let mut previous: Option<Option<Jongseong>> = None;
+
+    for (i, (cho, jung, jong)) in pronounced.iter().copied().enumerate() {
+        let voiced = match previous {
+            None => false,
+            Some(jong) => !matches!(
+                jong,
+                Some(Jongseong::Giyeok | Jongseong::Digeut | Jongseong::Bieup)
+            ),
+        };
+        let initial = match (previous, cho) {
+            (Some(Some(Jongseong::Rieul)), Choseong::Rieul) => "л",
+            (_, cho) => choseong(cho, voiced),
+        };
+
+        // ㅇ before a vowel is нъ, so as not to read н as the next initial: Пхёнъян
+        if previous == Some(Some(Jongseong::Ieung)) && initial.is_empty() {
+            transliterated.push('ъ');
+        }
+
+        transliterated.push_str(initial);
+        transliterated.push_str(jungseong(jung, initial.is_empty()));
+        if let Some(jong) = jong {
+            let next = pronounced.get(i + 1).map(|(cho, _, _)| *cho);
+            transliterated.push_str(jongseong(jong, next));
+        }
+        previous = Some(jong);
+    }
+
+    transliterated
+}
+
+/// Transliterates the Hangeul in the content by the Kontsevich system.
+/// Everything else is kept as it is.
+///
+/// ```rust
+/// use hangeul::kontsevich::transliterate;
+///
+/// assert_eq!("пусан", transliterate("부산"));
+/// assert_eq!("тэгу", transliterate("대구"));
+/// assert_eq!("соуль", transliterate("서울"));
+/// assert_eq!("пхёнъян", transliterate("평양"));
+/// ```
+pub fn transliterate(content: &str) -> String {
+    transliterate_with(content, Options::default())
+}
+
+/// Transliterates the Hangeul in the content. See `Options`.
+///
+/// ```rust
+/// use hangeul::kontsevich::{transliterate_with, Options};
+///
+/// let options = Options { capitalize: true, traditional: true };
+/// assert_eq!("Сеул", transliterate_with("서울", options));
+/// assert_eq!("Ли Сунсин", transliterate_with("이 순신", options));
+/// ```
+pub fn transliterate_with(content: &str, options: Options) -> String {
+    let words = split_words(content);
+    let first = words.iter().position(|(word, _)| !word.is_empty());
+    let transliterated: String = words
+        .iter()
+        .enumerate()
+        .map(|(i, (word, rest))| {
+            let surnames = SURNAMES.iter().filter(|_| Some(i) == first);
+            let traditional = TRADITIONAL
+                .iter()
+                .chain(surnames)
+                .find(|(hangeul, _)| hangeul.chars().filter_map(to_jamo).eq(word.iter().copied()));
+
+            match traditional {
+                Some((_, cyrillic)) if options.traditional => cyrillic.to_string() + rest,
+                _ => transliterate_word(word) + rest,
+            }
+        })
+        .collect();
+
+    match options.capitalize {
+        true => capitalize(&transliterated),
+        false => transliterated,
+    }
+}
@@ -1,9 +1,25 @@
+pub mod amount;
+pub mod archaic;
+pub mod autocorrect;
 pub mod constants;
 pub mod errors;
+pub mod ime;
+pub mod ipa;
+pub mod josa;
+pub mod keyboard;
+pub mod kontsevich;
+pub mod mccune_reischauer;
 pub mod models;
+pub mod normalize;
+pub mod number;
+pub mod pronunciation;
+pub mod revised;
+pub mod search;
+pub mod transliteration;
 
 use crate::constants::*;
 use crate::errors::*;
+use crate::ime::InputContext;
 use crate::models::*;
 
 /// Check if the u32 is a finished/composed Hangeul syllable.
@@ -71,7 +87,7 @@ pub fn is_compat_jamo(code: u32) -> bool {
 }
 
 /// Checks if a u32 is a (composable) Hangeul Syllable or Jamo.
-/// Archaic Korean is not supported at this time.
+/// Archaic Korean is only handled by the `archaic` module.
 /// See:
 /// * https://en.wikipedia.org/wiki/Hangul_Syllables.
 /// * https://en.wikipedia.org/wiki/Hangul_Jamo_(Unicode_block)
@@ -231,19 +247,19 @@ pub fn is_jongseong(code: u32) -> bool {
 /// Alias for is_choseong.
 pub use self::is_jongseong as is_tail;
 
-/// Checks if a str's last char ends in a consonant or not.
+/// Checks if a str ends in a consonant or not, as it is read aloud. See
+/// `josa::final_jongseong` for how digits, Latin letters and symbols are read.
 ///
 /// ```rust
 /// use hangeul::ends_with_jongseong; // ends_with_consonant
 ///
 /// assert_eq!(Ok(false), ends_with_jongseong("피카츄"));
 /// assert_eq!(Ok(true), ends_with_jongseong("이상해꽃"));
+/// assert_eq!(Ok(true), ends_with_jongseong("10"));
+/// assert_eq!(Ok(false), ends_with_jongseong("PC"));
 /// ```
 pub fn ends_with_jongseong(content: &str) -> Result<bool> {
-    match content.chars().last() {
-        Some(x) => has_jongseong(&x),
-        None => Err(HangeulError::NotASyllable),
-    }
+    josa::final_jongseong(content).map(|jong| jong.is_some())
 }
 /// Alias for ends_with_jongseong.
 pub use self::ends_with_jongseong as ends_in_consonant;
@@ -266,6 +282,82 @@ pub fn decompose(content: &str) -> Vec<Result<Decomposed>> {
     content.chars().map(|c| decompose_char(&c)).collect()
 }
 
+/// Decomposes a string into a flat string of compatibility jamo. With `split_compounds`,
+/// compound vowels and consonant clusters are split as well. Anything that isn't a
+/// Hangeul syllable is left alone.
+///
+/// ```rust
+/// use hangeul::decompose_to_jamo;
+///
+/// assert_eq!("ㅎㅏㄴㄱㅡㄹ", decompose_to_jamo("한글", false));
+/// assert_eq!("ㄱㅏㅄ", decompose_to_jamo("값", false));
+/// assert_eq!("ㄱㅏㅂㅅ", decompose_to_jamo("값", true));
+/// assert_eq!("ㄱㅗㅏ 2", decompose_to_jamo("과 2", true));
+/// ```
+pub fn decompose_to_jamo(content: &str, split_compounds: bool) -> String {
+    let mut jamo = Vec::new();
+
+    for c in content.chars() {
+        match decompose_char(&c) {
+            Ok((cho, jung, jong)) => jamo.extend([Some(cho), Some(jung), jong].iter().flatten()),
+            Err(_) => jamo.push(c),
+        }
+    }
+
+    match split_compounds {
+        true => jamo
+            .into_iter()
+            .flat_map(|c| match decompose_jamo(&c, false) {
+                Ok((first, second)) => vec![first, second],
+                Err(_) => vec![c],
+            })
+            .collect(),
+        false => jamo.into_iter().collect(),
+    }
+}
+
+/// Composes a flat string of compatibility jamo back into syllables, the way a
+/// 2-beolsik input method would: a consonant between two vowels starts the next
+/// syllable, even the last one of a compound final, and compound jamo are
+/// assembled from their parts. Anything else is
+/// left alone. See `decompose_to_jamo`.
+///
+/// ```rust
+/// use hangeul::compose;
+///
+/// assert_eq!("가나", compose("ㄱㅏㄴㅏ"));
+/// assert_eq!("값이", compose("ㄱㅏㅂㅅㅇㅣ"));
+/// assert_eq!("과자 2개", compose("ㄱㅗㅏㅈㅏ 2ㄱㅐ"));
+/// assert_eq!("갑시", compose("ㄱㅏㅄㅣ"));
+/// ```
+pub fn compose(jamo: &str) -> String {
+    let mut ic = InputContext::new();
+    let mut composed = String::new();
+
+    for c in jamo.chars() {
+        // a compound final is typed as its two consonants, so that the second one
+        // can still start the next syllable: ㄱㅏㅄㅣ -> 갑시
+        let open = matches!(
+            ic.preedit().map(|syllable| has_jongseong(&syllable)),
+            Some(Ok(false))
+        );
+        let parts = match is_compat_jamo(c as u32) {
+            true => Jongseong::from_jamo(c as u32).and_then(|jong| jong.split()),
+            false => None,
+        };
+        match parts {
+            Some((first, second)) if open => {
+                composed.push_str(&ic.process(first.to_char()).commit);
+                composed.push_str(&ic.process(second.to_char()).commit);
+            }
+            _ => composed.push_str(&ic.process(c).commit),
+        }
+    }
+    composed.push_str(&ic.flush());
+
+    composed
+}
+
 /// Attempts to decompose a char. Errors if the first and second glyphs
 /// aren't valid Korean jamo. See [Compatibility Jamo](https://en.wikipedia.org/wiki/Hangul_Compatibility_Jamo).
 ///
@@ -323,3 +415,75 @@ pub fn compose_char(choseong: &char, jungseong: &char, jongseong: Option<&char>)
 
     std::char::from_u32(code).ok_or_else(|| HangeulError::Uncomposable)
 }
+
+/// Attempts to combine two jamo into a compound jamo: a compound vowel (ㅗ + ㅏ -> ㅘ)
+/// or a consonant cluster (ㄹ + ㄱ -> ㄺ). Double consonants (ㄱ + ㄱ -> ㄲ) are only
+/// built when `doubles` is set, since input methods often type them with Shift instead.
+/// Returns a compatibility jamo.
+///
+/// ```rust
+/// use hangeul::compose_jamo;
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok('ㅘ'), compose_jamo(&'ㅗ', &'ㅏ', false));
+/// assert_eq!(Ok('ㄺ'), compose_jamo(&'ㄹ', &'ㄱ', false));
+/// assert_eq!(Ok('ㄲ'), compose_jamo(&'ㄱ', &'ㄱ', true));
+/// assert_eq!(Err(HangeulError::Uncomposable), compose_jamo(&'ㄱ', &'ㄱ', false));
+/// assert_eq!(Err(HangeulError::Uncomposable), compose_jamo(&'ㅏ', &'ㅗ', true));
+/// ```
+pub fn compose_jamo(first: &char, second: &char, doubles: bool) -> Result<char> {
+    let (first, second) = (*first as u32, *second as u32);
+
+    if let (Some(a), Some(b)) = (Jungseong::from_jamo(first), Jungseong::from_jamo(second)) {
+        return a
+            .combine(&b)
+            .map(|jung| jung.to_char())
+            .ok_or(HangeulError::Uncomposable);
+    }
+
+    if let (Some(a), Some(b)) = (Jongseong::from_jamo(first), Jongseong::from_jamo(second)) {
+        if let Some(jong) = a.combine(&b) {
+            return Ok(jong.to_char());
+        }
+    }
+
+    if doubles && first == second {
+        if let Some(cho) = Choseong::from_jamo(first).and_then(|cho| cho.double()) {
+            return Ok(cho.to_char());
+        }
+    }
+
+    Err(HangeulError::Uncomposable)
+}
+
+/// Attempts to split a compound jamo into the two jamo it is made of. See `compose_jamo`.
+///
+/// ```rust
+/// use hangeul::decompose_jamo;
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok(('ㅡ', 'ㅣ')), decompose_jamo(&'ㅢ', false));
+/// assert_eq!(Ok(('ㄹ', 'ㅎ')), decompose_jamo(&'ㅀ', false));
+/// assert_eq!(Ok(('ㅅ', 'ㅅ')), decompose_jamo(&'ㅆ', true));
+/// assert_eq!(Err(HangeulError::NotACompound), decompose_jamo(&'ㅆ', false));
+/// assert_eq!(Err(HangeulError::NotACompound), decompose_jamo(&'ㄱ', true));
+/// ```
+pub fn decompose_jamo(c: &char, doubles: bool) -> Result<(char, char)> {
+    let code = *c as u32;
+
+    if let Some((a, b)) = Jungseong::from_jamo(code).and_then(|jung| jung.split()) {
+        return Ok((a.to_char(), b.to_char()));
+    }
+
+    if let Some((a, b)) = Jongseong::from_jamo(code).and_then(|jong| jong.split()) {
+        return Ok((a.to_char(), b.to_char()));
+    }
+
+    if doubles {
+        if let Some((a, b)) = Choseong::from_jamo(code).and_then(|cho| cho.split_double()) {
+            return Ok((a.to_char(), b.to_char()));
+        }
+    }
+
+    Err(HangeulError::NotACompound)
+}
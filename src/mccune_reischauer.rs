@@ -0,0 +1,146 @@
+//! McCune–Reischauer romanization, used by libraries, in academic writing and for
+//! North Korean place names.
+//!
+//! Like the Revised Romanization it follows pronunciation, but it also marks
+//! aspiration with an apostrophe (k', t', p', ch'), voices ㄱ, ㄷ, ㅂ and ㅈ
+//! between voiced sounds (Pusan but Taegu), and writes ㅓ and ㅡ with breves.
+use crate::models::*;
+use crate::revised::{pronounce, split_words, Jamo};
+
+/// Plain consonants, written voiceless at the start of a word or after ㄱ, ㄷ
+/// and ㅂ, and voiced elsewhere.
+fn choseong(cho: Choseong, voiced: bool) -> &'static str {
+    match (cho, voiced) {
+        (Choseong::Giyeok, false) => "k",
+        (Choseong::Giyeok, true) => "g",
+        (Choseong::Digeut, false) => "t",
+        (Choseong::Digeut, true) => "d",
+        (Choseong::Bieup, false) => "p",
+        (Choseong::Bieup, true) => "b",
+        (Choseong::Jieut, false) => "ch",
+        (Choseong::Jieut, true) => "j",
+        (Choseong::SsangGiyeok, _) => "kk",
+        (Choseong::Nieun, _) => "n",
+        (Choseong::SsangDigeut, _) => "tt",
+        (Choseong::Rieul, _) => "r",
+        (Choseong::Mieum, _) => "m",
+        (Choseong::SsangBieup, _) => "pp",
+        (Choseong::Siot, _) => "s",
+        (Choseong::SsangSiot, _) => "ss",
+        (Choseong::Ieung, _) => "",
+        (Choseong::SsangJieut, _) => "tch",
+        (Choseong::Chieut, _) => "ch'",
+        (Choseong::Kieuk, _) => "k'",
+        (Choseong::Tieut, _) => "t'",
+        (Choseong::Pieup, _) => "p'",
+        (Choseong::Hieuh, _) => "h",
+    }
+}
+
+fn jungseong(jung: Jungseong) -> &'static str {
+    match jung {
+        Jungseong::A => "a",
+        Jungseong::AE => "ae",
+        Jungseong::YA => "ya",
+        Jungseong::YAE => "yae",
+        Jungseong::EO => "ŏ",
+        Jungseong::E => "e",
+        Jungseong::YEO => "yŏ",
+        Jungseong::YE => "ye",
+        Jungseong::O => "o",
+        Jungseong::WA => "wa",
+        Jungseong::WAE => "wae",
+        Jungseong::OE => "oe",
+        Jungseong::YO => "yo",
+        Jungseong::U => "u",
+        Jungseong::WEO => "wŏ",
+        Jungseong::WE => "we",
+        Jungseong::WI => "wi",
+        Jungseong::YU => "yu",
+        Jungseong::EU => "ŭ",
+        Jungseong::YI => "ŭi",
+        Jungseong::I => "i",
+    }
+}
+
+/// The final sounds left by `pronounce`.
+fn jongseong(jong: Jongseong) -> &'static str {
+    match jong {
+        Jongseong::Giyeok => "k",
+        Jongseong::Nieun => "n",
+        Jongseong::Digeut => "t",
+        Jongseong::Rieul => "l",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p",
+        _ => "ng",
+    }
+}
+
+fn romanize_word(word: &[Jamo]) -> String {
+    let mut romanized = String::new();
+    let mut previous: Option<Option<Jongseong>> = None;
+
+    for (cho, jung, jong) in pronounce(word) {
+        let voiced = match previous {
+            None => false,
+            Some(jong) => !matches!(
+                jong,
+                Some(Jongseong::Giyeok | Jongseong::Digeut | Jongseong::Bieup)
+            ),
+        };
+        let initial = match (previous, cho, jung) {
+            (Some(Some(Jongseong::Rieul)), Choseong::Rieul, _) => "l",
+            (_, Choseong::Siot, Jungseong::WI) => "sh",
+            (_, cho, _) => choseong(cho, voiced),
+        };
+
+        // n'g for ㄴ and ㄱ, so as not to read ㅇ: Han'gang. Likewise ng'a, but not
+        // before y and w: Chung'ang, P'yŏngyang
+        let next = format!("{}{}", initial, jungseong(jung));
+        let separate = match previous {
+            Some(Some(Jongseong::Nieun)) => next.starts_with('g'),
+            Some(Some(Jongseong::Ieung)) => !next.starts_with(['y', 'w']) && initial.is_empty(),
+            _ => false,
+        };
+        if separate {
+            romanized.push('\'');
+        }
+
+        romanized.push_str(initial);
+        romanized.push_str(jungseong(jung));
+        romanized.push_str(jong.map_or("", jongseong));
+        previous = Some(jong);
+    }
+
+    romanized
+}
+
+/// Romanizes the Hangeul in the content. Everything else is kept as it is.
+///
+/// ```rust
+/// use hangeul::mccune_reischauer::romanize;
+///
+/// assert_eq!("pusan", romanize("부산"));
+/// assert_eq!("taegu", romanize("대구"));
+/// assert_eq!("han'gŭl", romanize("한글"));
+/// assert_eq!("p'yŏngyang", romanize("평양"));
+/// ```
+pub fn romanize(content: &str) -> String {
+    split_words(content)
+        .iter()
+        .map(|(word, rest)| romanize_word(word) + rest)
+        .collect()
+}
+
+/// Romanizes the Hangeul in the content with ASCII letters only, writing ŏ as eo
+/// and ŭ as eu.
+///
+/// ```rust
+/// use hangeul::mccune_reischauer::romanize_ascii;
+///
+/// assert_eq!("p'yeongyang", romanize_ascii("평양"));
+/// assert_eq!("han'geul", romanize_ascii("한글"));
+/// ```
+pub fn romanize_ascii(content: &str) -> String {
+    romanize(content).replace('ŏ', "eo").replace('ŭ', "eu")
+}
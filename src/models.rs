@@ -7,7 +7,7 @@ pub trait Syllable {
     fn to_index(&self) -> u32;
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Choseong {
     Giyeok,
     SsangGiyeok,
@@ -97,6 +97,38 @@ impl Choseong {
             Choseong::Hieuh => 'ㅎ',
         }
     }
+
+    /// The double consonant (쌍자음) made of two of this consonant: ㄱ -> ㄲ.
+    ///
+    /// ```rust
+    /// use hangeul::models::Choseong;
+    ///
+    /// assert_eq!(Some(Choseong::SsangGiyeok), Choseong::Giyeok.double());
+    /// assert_eq!(None, Choseong::Nieun.double());
+    /// ```
+    pub fn double(&self) -> Option<Choseong> {
+        match self {
+            Choseong::Giyeok => Some(Choseong::SsangGiyeok),
+            Choseong::Digeut => Some(Choseong::SsangDigeut),
+            Choseong::Bieup => Some(Choseong::SsangBieup),
+            Choseong::Siot => Some(Choseong::SsangSiot),
+            Choseong::Jieut => Some(Choseong::SsangJieut),
+            _ => None,
+        }
+    }
+
+    /// Splits a double consonant into its halves: ㄲ -> (ㄱ, ㄱ).
+    pub fn split_double(&self) -> Option<(Choseong, Choseong)> {
+        let single = match self {
+            Choseong::SsangGiyeok => Choseong::Giyeok,
+            Choseong::SsangDigeut => Choseong::Digeut,
+            Choseong::SsangBieup => Choseong::Bieup,
+            Choseong::SsangSiot => Choseong::Siot,
+            Choseong::SsangJieut => Choseong::Jieut,
+            _ => return None,
+        };
+        Some((single, single))
+    }
 }
 
 impl Syllable for Choseong {
@@ -134,7 +166,7 @@ impl Syllable for Choseong {
 }
 
 /// `중성` -> Middle Sound (always a vowel)
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Jungseong {
     A,   // ㅏ
     AE,  // ㅐ
@@ -230,6 +262,52 @@ impl Jungseong {
             Jungseong::I => 'ㅣ',
         }
     }
+
+    /// Combines two vowels into a compound vowel (이중 모음): ㅗ + ㅏ -> ㅘ.
+    ///
+    /// ```rust
+    /// use hangeul::models::Jungseong;
+    ///
+    /// assert_eq!(Some(Jungseong::WA), Jungseong::O.combine(&Jungseong::A));
+    /// assert_eq!(None, Jungseong::A.combine(&Jungseong::O));
+    /// ```
+    pub fn combine(&self, other: &Jungseong) -> Option<Jungseong> {
+        match (self, other) {
+            (Jungseong::O, Jungseong::A) => Some(Jungseong::WA),
+            (Jungseong::O, Jungseong::AE) => Some(Jungseong::WAE),
+            (Jungseong::O, Jungseong::I) => Some(Jungseong::OE),
+            (Jungseong::U, Jungseong::EO) => Some(Jungseong::WEO),
+            (Jungseong::U, Jungseong::E) => Some(Jungseong::WE),
+            (Jungseong::U, Jungseong::I) => Some(Jungseong::WI),
+            (Jungseong::EU, Jungseong::I) => Some(Jungseong::YI),
+            _ => None,
+        }
+    }
+
+    /// Splits a compound vowel into the two vowels it is typed with: ㅘ -> (ㅗ, ㅏ).
+    ///
+    /// ```rust
+    /// use hangeul::models::Jungseong;
+    ///
+    /// assert_eq!(Some((Jungseong::EU, Jungseong::I)), Jungseong::YI.split());
+    /// assert_eq!(None, Jungseong::YA.split());
+    /// ```
+    pub fn split(&self) -> Option<(Jungseong, Jungseong)> {
+        match self {
+            Jungseong::WA => Some((Jungseong::O, Jungseong::A)),
+            Jungseong::WAE => Some((Jungseong::O, Jungseong::AE)),
+            Jungseong::OE => Some((Jungseong::O, Jungseong::I)),
+            Jungseong::WEO => Some((Jungseong::U, Jungseong::EO)),
+            Jungseong::WE => Some((Jungseong::U, Jungseong::E)),
+            Jungseong::WI => Some((Jungseong::U, Jungseong::I)),
+            Jungseong::YI => Some((Jungseong::EU, Jungseong::I)),
+            _ => None,
+        }
+    }
+
+    pub fn is_compound(&self) -> bool {
+        self.split().is_some()
+    }
 }
 
 impl Syllable for Jungseong {
@@ -268,7 +346,7 @@ impl Syllable for Jungseong {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Jongseong {
     Giyeok,
     SsangGiyeok,
@@ -381,6 +459,80 @@ impl Jongseong {
             Jongseong::Hieuh => 'ㅎ',
         }
     }
+
+    /// Combines two consonants into a consonant cluster (겹받침): ㄹ + ㄱ -> ㄺ.
+    /// Double consonants are not clusters, see `double`.
+    ///
+    /// ```rust
+    /// use hangeul::models::Jongseong;
+    ///
+    /// assert_eq!(Some(Jongseong::RieulGiyeok), Jongseong::Rieul.combine(&Jongseong::Giyeok));
+    /// assert_eq!(None, Jongseong::Giyeok.combine(&Jongseong::Giyeok));
+    /// ```
+    pub fn combine(&self, other: &Jongseong) -> Option<Jongseong> {
+        match (self, other) {
+            (Jongseong::Giyeok, Jongseong::Siot) => Some(Jongseong::GiyeokSiot),
+            (Jongseong::Nieun, Jongseong::Jieut) => Some(Jongseong::NieunJieut),
+            (Jongseong::Nieun, Jongseong::Hieuh) => Some(Jongseong::NieunHieuh),
+            (Jongseong::Rieul, Jongseong::Giyeok) => Some(Jongseong::RieulGiyeok),
+            (Jongseong::Rieul, Jongseong::Mieum) => Some(Jongseong::RieulMieum),
+            (Jongseong::Rieul, Jongseong::Bieup) => Some(Jongseong::RieulBieup),
+            (Jongseong::Rieul, Jongseong::Siot) => Some(Jongseong::RieulSiot),
+            (Jongseong::Rieul, Jongseong::Tieut) => Some(Jongseong::RieulTieut),
+            (Jongseong::Rieul, Jongseong::Pieup) => Some(Jongseong::RieulPieup),
+            (Jongseong::Rieul, Jongseong::Hieuh) => Some(Jongseong::RieulHieuh),
+            (Jongseong::Bieup, Jongseong::Siot) => Some(Jongseong::BieupSiot),
+            _ => None,
+        }
+    }
+
+    /// Splits a consonant cluster into its consonants: ㅀ -> (ㄹ, ㅎ).
+    ///
+    /// ```rust
+    /// use hangeul::models::Jongseong;
+    ///
+    /// assert_eq!(Some((Jongseong::Giyeok, Jongseong::Siot)), Jongseong::GiyeokSiot.split());
+    /// assert_eq!(None, Jongseong::SsangGiyeok.split());
+    /// ```
+    pub fn split(&self) -> Option<(Jongseong, Jongseong)> {
+        match self {
+            Jongseong::GiyeokSiot => Some((Jongseong::Giyeok, Jongseong::Siot)),
+            Jongseong::NieunJieut => Some((Jongseong::Nieun, Jongseong::Jieut)),
+            Jongseong::NieunHieuh => Some((Jongseong::Nieun, Jongseong::Hieuh)),
+            Jongseong::RieulGiyeok => Some((Jongseong::Rieul, Jongseong::Giyeok)),
+            Jongseong::RieulMieum => Some((Jongseong::Rieul, Jongseong::Mieum)),
+            Jongseong::RieulBieup => Some((Jongseong::Rieul, Jongseong::Bieup)),
+            Jongseong::RieulSiot => Some((Jongseong::Rieul, Jongseong::Siot)),
+            Jongseong::RieulTieut => Some((Jongseong::Rieul, Jongseong::Tieut)),
+            Jongseong::RieulPieup => Some((Jongseong::Rieul, Jongseong::Pieup)),
+            Jongseong::RieulHieuh => Some((Jongseong::Rieul, Jongseong::Hieuh)),
+            Jongseong::BieupSiot => Some((Jongseong::Bieup, Jongseong::Siot)),
+            _ => None,
+        }
+    }
+
+    pub fn is_compound(&self) -> bool {
+        self.split().is_some()
+    }
+
+    /// The double consonant (쌍자음) made of two of this consonant: ㅅ -> ㅆ.
+    pub fn double(&self) -> Option<Jongseong> {
+        match self {
+            Jongseong::Giyeok => Some(Jongseong::SsangGiyeok),
+            Jongseong::Siot => Some(Jongseong::SsangSiot),
+            _ => None,
+        }
+    }
+
+    /// Splits a double consonant into its halves: ㅆ -> (ㅅ, ㅅ).
+    pub fn split_double(&self) -> Option<(Jongseong, Jongseong)> {
+        let single = match self {
+            Jongseong::SsangGiyeok => Jongseong::Giyeok,
+            Jongseong::SsangSiot => Jongseong::Siot,
+            _ => return None,
+        };
+        Some((single, single))
+    }
 }
 
 impl Syllable for Jongseong {
@@ -424,3 +576,52 @@ impl Syllable for Jongseong {
         }
     }
 }
+
+/// `옛한글` -> Obsolete jamo that are still commonly found in historical texts.
+/// Recognized in compatibility form as well as in their conjoining choseong,
+/// jungseong and jongseong forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OldJamo {
+    AraeA,         // ㆍ
+    AraeAE,        // ㆎ
+    Bansiot,       // ㅿ
+    Yesieung,      // ㆁ
+    Yeorinhieuh,   // ㆆ
+    KapyeounMieum, // ㅱ
+    KapyeounBieup, // ㅸ
+}
+
+impl OldJamo {
+    pub fn from_char(c: &char) -> Option<OldJamo> {
+        OldJamo::from_u32(*c as u32)
+    }
+
+    pub fn from_u32(code: u32) -> Option<OldJamo> {
+        match code {
+            0x318D | 0x119E => Some(OldJamo::AraeA),
+            0x318E | 0x11A1 => Some(OldJamo::AraeAE),
+            0x317F | 0x1140 | 0x11EB => Some(OldJamo::Bansiot),
+            0x3181 | 0x114C | 0x11F0 => Some(OldJamo::Yesieung),
+            0x3186 | 0x1159 | 0x11F9 => Some(OldJamo::Yeorinhieuh),
+            0x3171 | 0x111D | 0x11E2 => Some(OldJamo::KapyeounMieum),
+            0x3178 | 0x112B | 0x11E6 => Some(OldJamo::KapyeounBieup),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match self {
+            OldJamo::AraeA => 'ㆍ',
+            OldJamo::AraeAE => 'ㆎ',
+            OldJamo::Bansiot => 'ㅿ',
+            OldJamo::Yesieung => 'ㆁ',
+            OldJamo::Yeorinhieuh => 'ㆆ',
+            OldJamo::KapyeounMieum => 'ㅱ',
+            OldJamo::KapyeounBieup => 'ㅸ',
+        }
+    }
+
+    pub fn is_vowel(&self) -> bool {
+        matches!(self, OldJamo::AraeA | OldJamo::AraeAE)
+    }
+}
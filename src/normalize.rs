@@ -0,0 +1,317 @@
+//! Unicode normalization of Hangeul.
+//!
+//! Syllables decompose canonically into conjoining jamo (`1100`–`11FF`), not into
+//! the compatibility jamo returned by `decompose_char`: 한 is `1112 1161 11AB` in
+//! NFD. Text coming from macOS file systems is usually in this form.
+//! See the Hangul section of [Unicode chapter 3.12](https://www.unicode.org/versions/latest/ch03.pdf).
+//!
+//! Legacy text may also carry halfwidth, circled or parenthesized Hangeul, which
+//! `fold` maps back to regular jamo and syllables.
+use crate::constants::*;
+use crate::is_syllable;
+
+/// Decomposes every syllable into conjoining jamo (Unicode NFD, for Hangeul).
+/// Anything else is left alone.
+///
+/// ```rust
+/// use hangeul::normalize::to_nfd;
+///
+/// assert_eq!("\u{1112}\u{1161}\u{11AB}", to_nfd("한"));
+/// assert_eq!("\u{1100}\u{1161} ok", to_nfd("가 ok"));
+/// ```
+pub fn to_nfd(content: &str) -> String {
+    let mut decomposed = String::with_capacity(content.len() * 3);
+
+    for c in content.chars() {
+        let code = c as u32;
+        if !is_syllable(code) {
+            decomposed.push(c);
+            continue;
+        }
+
+        let index = code - HANGEUL_OFFSET;
+        let jamo = [
+            CHOSEONG_START + index / CHOSEONG_COUNT,
+            JUNGSEONG_START + (index % CHOSEONG_COUNT) / JUNGSEONG_COUNT,
+            JONGSEONG_START - 1 + index % JUNGSEONG_COUNT,
+        ];
+
+        jamo.iter()
+            .filter(|j| **j != JONGSEONG_START - 1)
+            .filter_map(|j| std::char::from_u32(*j))
+            .for_each(|j| decomposed.push(j));
+    }
+
+    decomposed
+}
+
+/// Composes conjoining jamo sequences into syllables (Unicode NFC, for Hangeul).
+/// A choseong and a jungseong make a syllable, which takes a following jongseong.
+/// Partial sequences, fillers (`115F`, `1160`) and archaic jamo stay as they are.
+///
+/// ```rust
+/// use hangeul::normalize::to_nfc;
+///
+/// assert_eq!("한", to_nfc("\u{1112}\u{1161}\u{11AB}"));
+/// assert_eq!("각", to_nfc("가\u{11A8}"));
+/// assert_eq!("\u{1112}", to_nfc("\u{1112}"));
+/// assert_eq!("\u{115F}\u{1161}", to_nfc("\u{115F}\u{1161}"));
+/// ```
+pub fn to_nfc(content: &str) -> String {
+    let mut composed: Vec<char> = Vec::with_capacity(content.len());
+
+    for c in content.chars() {
+        let code = c as u32;
+        let last = composed.last().map(|l| *l as u32);
+
+        let syllable = match last {
+            Some(l) => compose_pair(l, code),
+            None => None,
+        };
+
+        match syllable.and_then(std::char::from_u32) {
+            Some(s) => {
+                composed.pop();
+                composed.push(s);
+            }
+            None => composed.push(c),
+        }
+    }
+
+    composed.into_iter().collect()
+}
+
+/// Folds a halfwidth (`FFA0`–`FFDC`), circled (`3260`–`327E`) or parenthesized
+/// (`3200`–`321E`) Hangeul char into regular compatibility jamo and syllables,
+/// as NFKC would except that jamo stay in their compatibility form.
+///
+/// ```rust
+/// use hangeul::normalize::fold_char;
+///
+/// assert_eq!(Some("ㄱ"), fold_char(&'ﾡ'));
+/// assert_eq!(Some("가"), fold_char(&'㉮'));
+/// assert_eq!(Some("(주)"), fold_char(&'㈜'));
+/// assert_eq!(None, fold_char(&'가'));
+/// ```
+pub fn fold_char(c: &char) -> Option<&'static str> {
+    let folded = match c {
+        '\u{FFA0}' => "\u{3164}",
+        '\u{FFA1}' => "ㄱ",     // ﾡ
+        '\u{FFA2}' => "ㄲ",     // ﾢ
+        '\u{FFA3}' => "ㄳ",     // ﾣ
+        '\u{FFA4}' => "ㄴ",     // ﾤ
+        '\u{FFA5}' => "ㄵ",     // ﾥ
+        '\u{FFA6}' => "ㄶ",     // ﾦ
+        '\u{FFA7}' => "ㄷ",     // ﾧ
+        '\u{FFA8}' => "ㄸ",     // ﾨ
+        '\u{FFA9}' => "ㄹ",     // ﾩ
+        '\u{FFAA}' => "ㄺ",     // ﾪ
+        '\u{FFAB}' => "ㄻ",     // ﾫ
+        '\u{FFAC}' => "ㄼ",     // ﾬ
+        '\u{FFAD}' => "ㄽ",     // ﾭ
+        '\u{FFAE}' => "ㄾ",     // ﾮ
+        '\u{FFAF}' => "ㄿ",     // ﾯ
+        '\u{FFB0}' => "ㅀ",     // ﾰ
+        '\u{FFB1}' => "ㅁ",     // ﾱ
+        '\u{FFB2}' => "ㅂ",     // ﾲ
+        '\u{FFB3}' => "ㅃ",     // ﾳ
+        '\u{FFB4}' => "ㅄ",     // ﾴ
+        '\u{FFB5}' => "ㅅ",     // ﾵ
+        '\u{FFB6}' => "ㅆ",     // ﾶ
+        '\u{FFB7}' => "ㅇ",     // ﾷ
+        '\u{FFB8}' => "ㅈ",     // ﾸ
+        '\u{FFB9}' => "ㅉ",     // ﾹ
+        '\u{FFBA}' => "ㅊ",     // ﾺ
+        '\u{FFBB}' => "ㅋ",     // ﾻ
+        '\u{FFBC}' => "ㅌ",     // ﾼ
+        '\u{FFBD}' => "ㅍ",     // ﾽ
+        '\u{FFBE}' => "ㅎ",     // ﾾ
+        '\u{FFC2}' => "ㅏ",     // ￂ
+        '\u{FFC3}' => "ㅐ",     // ￃ
+        '\u{FFC4}' => "ㅑ",     // ￄ
+        '\u{FFC5}' => "ㅒ",     // ￅ
+        '\u{FFC6}' => "ㅓ",     // ￆ
+        '\u{FFC7}' => "ㅔ",     // ￇ
+        '\u{FFCA}' => "ㅕ",     // ￊ
+        '\u{FFCB}' => "ㅖ",     // ￋ
+        '\u{FFCC}' => "ㅗ",     // ￌ
+        '\u{FFCD}' => "ㅘ",     // ￍ
+        '\u{FFCE}' => "ㅙ",     // ￎ
+        '\u{FFCF}' => "ㅚ",     // ￏ
+        '\u{FFD2}' => "ㅛ",     // ￒ
+        '\u{FFD3}' => "ㅜ",     // ￓ
+        '\u{FFD4}' => "ㅝ",     // ￔ
+        '\u{FFD5}' => "ㅞ",     // ￕ
+        '\u{FFD6}' => "ㅟ",     // ￖ
+        '\u{FFD7}' => "ㅠ",     // ￗ
+        '\u{FFDA}' => "ㅡ",     // ￚ
+        '\u{FFDB}' => "ㅢ",     // ￛ
+        '\u{FFDC}' => "ㅣ",     // ￜ
+        '\u{3200}' => "(ㄱ)",   // ㈀
+        '\u{3201}' => "(ㄴ)",   // ㈁
+        '\u{3202}' => "(ㄷ)",   // ㈂
+        '\u{3203}' => "(ㄹ)",   // ㈃
+        '\u{3204}' => "(ㅁ)",   // ㈄
+        '\u{3205}' => "(ㅂ)",   // ㈅
+        '\u{3206}' => "(ㅅ)",   // ㈆
+        '\u{3207}' => "(ㅇ)",   // ㈇
+        '\u{3208}' => "(ㅈ)",   // ㈈
+        '\u{3209}' => "(ㅊ)",   // ㈉
+        '\u{320A}' => "(ㅋ)",   // ㈊
+        '\u{320B}' => "(ㅌ)",   // ㈋
+        '\u{320C}' => "(ㅍ)",   // ㈌
+        '\u{320D}' => "(ㅎ)",   // ㈍
+        '\u{320E}' => "(가)",   // ㈎
+        '\u{320F}' => "(나)",   // ㈏
+        '\u{3210}' => "(다)",   // ㈐
+        '\u{3211}' => "(라)",   // ㈑
+        '\u{3212}' => "(마)",   // ㈒
+        '\u{3213}' => "(바)",   // ㈓
+        '\u{3214}' => "(사)",   // ㈔
+        '\u{3215}' => "(아)",   // ㈕
+        '\u{3216}' => "(자)",   // ㈖
+        '\u{3217}' => "(차)",   // ㈗
+        '\u{3218}' => "(카)",   // ㈘
+        '\u{3219}' => "(타)",   // ㈙
+        '\u{321A}' => "(파)",   // ㈚
+        '\u{321B}' => "(하)",   // ㈛
+        '\u{321C}' => "(주)",   // ㈜
+        '\u{321D}' => "(오전)", // ㈝
+        '\u{321E}' => "(오후)", // ㈞
+        '\u{3260}' => "ㄱ",     // ㉠
+        '\u{3261}' => "ㄴ",     // ㉡
+        '\u{3262}' => "ㄷ",     // ㉢
+        '\u{3263}' => "ㄹ",     // ㉣
+        '\u{3264}' => "ㅁ",     // ㉤
+        '\u{3265}' => "ㅂ",     // ㉥
+        '\u{3266}' => "ㅅ",     // ㉦
+        '\u{3267}' => "ㅇ",     // ㉧
+        '\u{3268}' => "ㅈ",     // ㉨
+        '\u{3269}' => "ㅊ",     // ㉩
+        '\u{326A}' => "ㅋ",     // ㉪
+        '\u{326B}' => "ㅌ",     // ㉫
+        '\u{326C}' => "ㅍ",     // ㉬
+        '\u{326D}' => "ㅎ",     // ㉭
+        '\u{326E}' => "가",     // ㉮
+        '\u{326F}' => "나",     // ㉯
+        '\u{3270}' => "다",     // ㉰
+        '\u{3271}' => "라",     // ㉱
+        '\u{3272}' => "마",     // ㉲
+        '\u{3273}' => "바",     // ㉳
+        '\u{3274}' => "사",     // ㉴
+        '\u{3275}' => "아",     // ㉵
+        '\u{3276}' => "자",     // ㉶
+        '\u{3277}' => "차",     // ㉷
+        '\u{3278}' => "카",     // ㉸
+        '\u{3279}' => "타",     // ㉹
+        '\u{327A}' => "파",     // ㉺
+        '\u{327B}' => "하",     // ㉻
+        '\u{327C}' => "참고",   // ㉼
+        '\u{327D}' => "주의",   // ㉽
+        '\u{327E}' => "우",     // ㉾
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// Folds every halfwidth, circled and parenthesized Hangeul char of the content,
+/// so that "㈜한글" and "(주)한글" compare equal. See `fold_char`.
+///
+/// ```rust
+/// use hangeul::normalize::fold;
+///
+/// assert_eq!("(주)한글", fold("㈜한글"));
+/// assert_eq!("ㄱ. 가나다", fold("㉠. ㉮㉯㉰"));
+/// assert_eq!("한", hangeul::compose(&fold("\u{FFBE}\u{FFC2}\u{FFA4}")));
+/// ```
+pub fn fold(content: &str) -> String {
+    let mut folded = String::with_capacity(content.len());
+
+    for c in content.chars() {
+        match fold_char(&c) {
+            Some(f) => folded.push_str(f),
+            None => folded.push(c),
+        }
+    }
+
+    folded
+}
+
+/// Like `hangeul::is_hangeul`, but also accepting halfwidth, circled and
+/// parenthesized Hangeul.
+///
+/// ```rust
+/// use hangeul::normalize;
+///
+/// assert_eq!(false, hangeul::is_hangeul('㈜' as u32));
+/// assert_eq!(true, normalize::is_hangeul('㈜' as u32));
+/// assert_eq!(true, normalize::is_hangeul('ﾡ' as u32));
+/// ```
+pub fn is_hangeul(code: u32) -> bool {
+    crate::is_hangeul(code) || folds_into_hangeul(code)
+}
+
+/// Like `hangeul::is_jaeum`, but also accepting halfwidth, circled and
+/// parenthesized consonants.
+///
+/// ```rust
+/// use hangeul::normalize;
+///
+/// assert_eq!(true, normalize::is_jaeum('㉠' as u32));
+/// assert_eq!(true, normalize::is_jaeum('㈀' as u32));
+/// assert_eq!(false, normalize::is_jaeum('㉮' as u32));
+/// ```
+pub fn is_jaeum(code: u32) -> bool {
+    match folded_char(code) {
+        Some(c) => crate::is_jaeum(c as u32),
+        None => crate::is_jaeum(code),
+    }
+}
+
+/// Like `hangeul::is_moeum`, but also accepting halfwidth vowels.
+pub fn is_moeum(code: u32) -> bool {
+    match folded_char(code) {
+        Some(c) => crate::is_moeum(c as u32),
+        None => crate::is_moeum(code),
+    }
+}
+
+/// The single Hangeul char a char folds into, parentheses aside.
+fn folded_char(code: u32) -> Option<char> {
+    let folded = std::char::from_u32(code).and_then(|c| fold_char(&c))?;
+    let mut chars = folded.trim_start_matches('(').trim_end_matches(')').chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Whether the char folds into Hangeul, parentheses aside.
+fn folds_into_hangeul(code: u32) -> bool {
+    let folded = std::char::from_u32(code).and_then(|c| fold_char(&c));
+    match folded {
+        Some(f) => f
+            .chars()
+            .filter(|c| *c != '(' && *c != ')')
+            .all(|c| crate::is_hangeul(c as u32)),
+        None => false,
+    }
+}
+
+/// Composes a choseong with a jungseong, or a syllable without a jongseong with a jongseong.
+fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    match (first, second) {
+        (CHOSEONG_START..=CHOSEONG_END, JUNGSEONG_START..=JUNGSEONG_END) => {
+            let cho = first - CHOSEONG_START;
+            let jung = second - JUNGSEONG_START;
+            Some(HANGEUL_OFFSET + cho * CHOSEONG_COUNT + jung * JUNGSEONG_COUNT)
+        }
+        (SYLLABLE_START..=SYLLABLE_END, JONGSEONG_START..=JONGSEONG_END)
+            if (first - HANGEUL_OFFSET) % JUNGSEONG_COUNT == 0 =>
+        {
+            Some(first + second - (JONGSEONG_START - 1))
+        }
+        _ => None,
+    }
+}
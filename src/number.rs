@@ -0,0 +1,385 @@
+//! Reading numbers in Hangeul, the Sino-Korean way (일, 이, 삼) used for most
+//! numbers and the native way (하나, 둘, 셋) used for counting things up to 99.
+use crate::errors::*;
+use std::convert::TryFrom;
+
+/// Sino-Korean digits, from 0 to 9.
+pub const SINO_DIGITS: [&str; 10] = ["영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+
+/// Units within a group of four digits, for the thousands, hundreds and tens.
+const SINO_UNITS: [&str; 4] = ["천", "백", "십", ""];
+
+/// Units of each group of four digits.
+pub const SINO_GROUPS: [&str; 5] = ["", "만", "억", "조", "경"];
+
+const NATIVE_ONES: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// Reads a group of up to four digits, omitting the 일 before 십, 백 and 천.
+fn sino_group(n: u64) -> String {
+    let digits = [n / 1000, n / 100 % 10, n / 10 % 10, n % 10];
+
+    digits
+        .iter()
+        .zip(SINO_UNITS.iter())
+        .filter(|(digit, _)| **digit != 0)
+        .map(|(digit, unit)| match (*digit, *unit) {
+            (1, unit) if !unit.is_empty() => unit.to_string(),
+            (digit, unit) => format!("{}{}", SINO_DIGITS[digit as usize], unit),
+        })
+        .collect()
+}
+
+/// Reads an integer in Sino-Korean. Groups of four digits are spaced apart, and
+/// the 일 is omitted before 십, 백, 천 and 만.
+///
+/// ```rust
+/// use hangeul::number::sino;
+///
+/// assert_eq!("만 이천삼백사십오", sino(12345));
+/// assert_eq!("일억 이천만", sino(120_000_000));
+/// assert_eq!("영", sino(0));
+/// assert_eq!("마이너스 십", sino(-10));
+/// ```
+pub fn sino(n: i64) -> String {
+    if n == 0 {
+        return SINO_DIGITS[0].to_string();
+    }
+
+    let mut rest = n.unsigned_abs();
+    let mut groups = Vec::new();
+    for unit in SINO_GROUPS.iter() {
+        let group = rest % 10000;
+        rest /= 10000;
+
+        if group == 0 {
+            continue;
+        }
+        groups.push(match (group, *unit) {
+            (1, "만") => "만".to_string(),
+            (group, unit) => format!("{}{}", sino_group(group), unit),
+        });
+    }
+    groups.reverse();
+
+    match n < 0 {
+        true => format!("마이너스 {}", groups.join(" ")),
+        false => groups.join(" "),
+    }
+}
+
+/// Reads a decimal number in Sino-Korean. Digits after the point are read one by
+/// one. NaN, infinities and numbers whose integer part doesn't fit an i64 are
+/// out of range.
+///
+/// ```rust
+/// use hangeul::number::sino_decimal;
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok("삼 점 일사".to_string()), sino_decimal(3.14));
+/// assert_eq!(Ok("영 점 오".to_string()), sino_decimal(0.5));
+/// assert_eq!(Ok("십".to_string()), sino_decimal(10.0));
+/// assert_eq!(Err(HangeulError::OutOfRange), sino_decimal(f64::NAN));
+/// ```
+pub fn sino_decimal(n: f64) -> Result<String> {
+    if !n.is_finite() {
+        return Err(HangeulError::OutOfRange);
+    }
+    let written = n.abs().to_string();
+    let (integer, fraction) = written.split_once('.').unwrap_or((&written, ""));
+
+    let integer = integer
+        .parse::<i64>()
+        .map_err(|_| HangeulError::OutOfRange)?;
+    let mut read = sino(integer);
+    if !fraction.is_empty() {
+        read.push_str(" 점 ");
+        fraction
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .for_each(|d| read.push_str(SINO_DIGITS[d as usize]));
+    }
+
+    match n < 0.0 {
+        true => Ok(format!("마이너스 {}", read)),
+        false => Ok(read),
+    }
+}
+
+/// Reads a number from 1 to 99 in native Korean. The attributive form is the one
+/// used before a counter: 한 개, 두 명, 스무 살.
+///
+/// ```rust
+/// use hangeul::number::native;
+///
+/// assert_eq!(Ok("스물셋".to_string()), native(23, false));
+/// assert_eq!(Ok("스물세".to_string()), native(23, true));
+/// assert_eq!(Ok("스무".to_string()), native(20, true));
+/// ```
+pub fn native(n: u32, attributive: bool) -> Result<String> {
+    if !(1..100).contains(&n) {
+        return Err(HangeulError::OutOfRange);
+    }
+
+    let (tens, ones) = ((n / 10) as usize, (n % 10) as usize);
+    let read = match (attributive, tens, ones) {
+        (true, 2, 0) => "스무".to_string(),
+        (true, tens, 1..=4) => format!(
+            "{}{}",
+            NATIVE_TENS[tens],
+            ["한", "두", "세", "네"][ones - 1]
+        ),
+        (_, tens, ones) => format!("{}{}", NATIVE_TENS[tens], NATIVE_ONES[ones]),
+    };
+
+    Ok(read)
+}
+
+/// Reads an ordinal number from 1 to 99: 첫째, 둘째, 열한째. With a counter, the
+/// ordinal is the one used for order and repetition: 첫 번째, 세 번째.
+///
+/// ```rust
+/// use hangeul::number::ordinal;
+///
+/// assert_eq!(Ok("첫째".to_string()), ordinal(1, false));
+/// assert_eq!(Ok("열두째".to_string()), ordinal(12, false));
+/// assert_eq!(Ok("세 번째".to_string()), ordinal(3, true));
+/// ```
+pub fn ordinal(n: u32, counter: bool) -> Result<String> {
+    let read = match (n, counter) {
+        (1, _) => "첫".to_string(),
+        (2, false) => "둘".to_string(),
+        (n, false) if n % 10 == 3 || n % 10 == 4 => native(n, false)?,
+        (n, _) => native(n, true)?,
+    };
+
+    match counter {
+        true => Ok(format!("{} 번째", read)),
+        false => Ok(format!("{}째", read)),
+    }
+}
+
+/// A word of a written number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    /// A Sino-Korean digit or a run of Arabic digits
+    Number(u64),
+    /// 십, 백 or 천
+    Unit(u64),
+    /// 만, 억, 조 or 경
+    Group(u64),
+    NativeTens(u64),
+    NativeOnes(u64),
+}
+
+/// Words of written numbers. 일흔 comes before 일 so that it is matched whole.
+const WORDS: [(&str, Token); 42] = [
+    ("일흔", Token::NativeTens(70)),
+    ("열", Token::NativeTens(10)),
+    ("스물", Token::NativeTens(20)),
+    ("스무", Token::NativeTens(20)),
+    ("서른", Token::NativeTens(30)),
+    ("마흔", Token::NativeTens(40)),
+    ("쉰", Token::NativeTens(50)),
+    ("예순", Token::NativeTens(60)),
+    ("여든", Token::NativeTens(80)),
+    ("아흔", Token::NativeTens(90)),
+    ("하나", Token::NativeOnes(1)),
+    ("한", Token::NativeOnes(1)),
+    ("둘", Token::NativeOnes(2)),
+    ("두", Token::NativeOnes(2)),
+    ("셋", Token::NativeOnes(3)),
+    ("세", Token::NativeOnes(3)),
+    ("넷", Token::NativeOnes(4)),
+    ("네", Token::NativeOnes(4)),
+    ("다섯", Token::NativeOnes(5)),
+    ("여섯", Token::NativeOnes(6)),
+    ("일곱", Token::NativeOnes(7)),
+    ("여덟", Token::NativeOnes(8)),
+    ("아홉", Token::NativeOnes(9)),
+    ("영", Token::Number(0)),
+    ("공", Token::Number(0)),
+    ("일", Token::Number(1)),
+    ("이", Token::Number(2)),
+    ("삼", Token::Number(3)),
+    ("사", Token::Number(4)),
+    ("오", Token::Number(5)),
+    ("육", Token::Number(6)),
+    ("륙", Token::Number(6)),
+    ("칠", Token::Number(7)),
+    ("팔", Token::Number(8)),
+    ("구", Token::Number(9)),
+    ("십", Token::Unit(10)),
+    ("백", Token::Unit(100)),
+    ("천", Token::Unit(1000)),
+    ("만", Token::Group(10_000)),
+    ("억", Token::Group(100_000_000)),
+    ("조", Token::Group(1_000_000_000_000)),
+    ("경", Token::Group(10_000_000_000_000_000)),
+];
+
+/// Reads the token at the given char position, returning it with its length.
+fn token_at(chars: &[char], start: usize) -> Option<(Token, usize)> {
+    if chars[start].is_ascii_digit() {
+        let mut number: u64 = 0;
+        let mut end = start;
+        while end < chars.len() {
+            match chars[end] {
+                c @ '0'..='9' => {
+                    number = number
+                        .checked_mul(10)?
+                        .checked_add(c.to_digit(10)? as u64)?;
+                }
+                // thousands separators, as in 2,345
+                ',' if chars.get(end + 1).is_some_and(|c| c.is_ascii_digit()) => (),
+                _ => break,
+            }
+            end += 1;
+        }
+        return Some((Token::Number(number), end - start));
+    }
+
+    WORDS.iter().find_map(|(word, token)| {
+        let len = word.chars().count();
+        match chars.get(start..start + len) {
+            Some(found) if found.iter().copied().eq(word.chars()) => Some((*token, len)),
+            _ => None,
+        }
+    })
+}
+
+/// Parses a number written in Hangeul, Arabic digits, or a mix of both, as in
+/// "삼천오백", "스물다섯", "1억 2천만" or "3만5천". Spaces are ignored. Errors with the
+/// char position of the first thing that can't be read.
+///
+/// ```rust
+/// use hangeul::number::parse;
+/// use hangeul::errors::HangeulError;
+///
+/// assert_eq!(Ok(210_000), parse("이십일만"));
+/// assert_eq!(Ok(25), parse("스물다섯"));
+/// assert_eq!(Ok(35_000), parse("3만5천"));
+/// assert_eq!(Err(HangeulError::InvalidNumber(2)), parse("삼천천"));
+/// ```
+pub fn parse(content: &str) -> Result<i64> {
+    let (negative, value) = parse_magnitude(content)?;
+
+    // the magnitude is negated as is, so that i64::MIN can be read
+    let value = match negative {
+        true => 0i64.checked_sub_unsigned(value),
+        false => i64::try_from(value).ok(),
+    };
+    value.ok_or(HangeulError::OutOfRange)
+}
+
+/// Parses a number into whether it is negative and its magnitude, which may go
+/// past i64::MAX.
+pub(crate) fn parse_magnitude(content: &str) -> Result<(bool, u64)> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens = Vec::new();
+    let mut negative = false;
+
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if tokens.is_empty() && !negative {
+            if chars[i] == '-' {
+                negative = true;
+                i += 1;
+                continue;
+            }
+            if chars[i..].starts_with(&['마', '이', '너', '스']) {
+                negative = true;
+                i += 4;
+                continue;
+            }
+        }
+
+        match token_at(&chars, i) {
+            Some((token, len)) => {
+                tokens.push((i, token));
+                i += len;
+            }
+            None => return Err(HangeulError::InvalidNumber(i)),
+        }
+    }
+
+    let value = match tokens.first() {
+        None => return Err(HangeulError::InvalidNumber(chars.len())),
+        Some((_, Token::NativeTens(_) | Token::NativeOnes(_))) => parse_native(&tokens)?,
+        Some(_) => parse_sino(&tokens)?,
+    };
+
+    Ok((negative, value))
+}
+
+/// Adds up native words, which are a ten and a one at most: 스물, 다섯, 스물다섯.
+fn parse_native(tokens: &[(usize, Token)]) -> Result<u64> {
+    let mut value = 0;
+    let mut seen_ones = false;
+
+    for (i, (position, token)) in tokens.iter().enumerate() {
+        match token {
+            Token::NativeTens(tens) if i == 0 => value += tens,
+            Token::NativeOnes(ones) if !seen_ones => {
+                value += ones;
+                seen_ones = true;
+            }
+            _ => return Err(HangeulError::InvalidNumber(*position)),
+        }
+    }
+
+    Ok(value)
+}
+
+/// Adds up Sino-Korean words and Arabic digits. Units must come in decreasing
+/// order: 천 before 백 within a group, and 억 before 만.
+fn parse_sino(tokens: &[(usize, Token)]) -> Result<u64> {
+    let mut total: u64 = 0;
+    let mut group: u64 = 0;
+    let mut number: Option<u64> = None;
+    let mut last_unit = u64::MAX;
+    let mut last_group = u64::MAX;
+
+    for (position, token) in tokens.iter() {
+        let invalid = HangeulError::InvalidNumber(*position);
+
+        match *token {
+            Token::Number(n) if number.is_none() => number = Some(n),
+            Token::Unit(unit) if unit < last_unit && number.unwrap_or(1) < 10 => {
+                group += number.take().unwrap_or(1) * unit;
+                last_unit = unit;
+            }
+            Token::Group(unit) if unit < last_group => {
+                let count = group
+                    .checked_add(number.take().unwrap_or(0))
+                    .ok_or(HangeulError::OutOfRange)?;
+                let count = match count {
+                    0 => 1,
+                    count => count,
+                };
+                total = count
+                    .checked_mul(unit)
+                    .and_then(|value| value.checked_add(total))
+                    .ok_or(HangeulError::OutOfRange)?;
+                group = 0;
+                last_unit = u64::MAX;
+                last_group = unit;
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    group
+        .checked_add(number.unwrap_or(0))
+        .and_then(|value| value.checked_add(total))
+        .ok_or(HangeulError::OutOfRange)
+}
@@ -0,0 +1,287 @@
+//! Standard pronunciation (표준 발음법), written back in Hangeul.
+//!
+//! The rules are applied at every syllable boundary of a word, in a fixed order,
+//! and each change can be inspected: 국물 is pronounced 궁물 because ㄱ before ㅁ
+//! becomes ㅇ.
+//!
+//! Words are taken as they are written, without knowing where their morphemes
+//! meet, so the exceptions for verb stems (맑게 -> 말께) and the added ㄴ of
+//! compounds (솜이불 -> 솜니불) are not applied. The one exception that only
+//! depends on the syllable is: ㄼ of 밟 is ㅂ before a consonant (밟고 -> 밥꼬).
+use crate::compose_char;
+use crate::models::*;
+use crate::revised::{nasalize, neutralize, split_words, Jamo};
+
+/// A rule of the standard pronunciation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// ㅎ is silent before a vowel (좋아 -> 조아), and makes ㅅ tense (닿소 -> 다쏘).
+    HieuhDeletion,
+    /// 거센소리되기: ㅎ and ㄱ, ㄷ, ㅂ or ㅈ become one aspirated consonant: 좋고 ->
+    /// 조코, 축하 -> 추카.
+    Aspiration,
+    /// 구개음화: ㄷ and ㅌ become ㅈ and ㅊ before 이: 같이 -> 가치.
+    Palatalization,
+    /// 연음: a jongseong moves over to a following vowel: 옷이 -> 오시.
+    Liaison,
+    /// 자음군 단순화: a compound jongseong keeps one of its consonants: 닭 -> 닥.
+    Simplification,
+    /// 음절의 끝소리 규칙: a jongseong is pronounced as one of ㄱ, ㄴ, ㄷ, ㄹ, ㅁ, ㅂ
+    /// and ㅇ: 옷 -> 옫.
+    Neutralization,
+    /// 유음화: ㄴ next to ㄹ becomes ㄹ: 신라 -> 실라.
+    Lateralization,
+    /// 비음화: ㄱ, ㄷ and ㅂ before a nasal become ㅇ, ㄴ and ㅁ (국물 -> 궁물), and
+    /// ㄹ after a consonant other than ㄴ becomes ㄴ (종로 -> 종노).
+    Nasalization,
+    /// 된소리되기: ㄱ, ㄷ, ㅂ, ㅅ and ㅈ after ㄱ, ㄷ or ㅂ become tense: 학교 -> 학꾜.
+    Tensification,
+}
+
+/// The jongseong of a syllable and the choseong of the one after it. The
+/// choseong is `None` at the end of a word.
+pub type Boundary = (Option<Jongseong>, Option<Choseong>);
+
+/// A rule that changed the sounds at a syllable boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    /// The char position of the syllable before the boundary.
+    pub position: usize,
+    pub rule: Rule,
+    pub before: Boundary,
+    pub after: Boundary,
+}
+
+/// The order in which the rules are tried at each boundary.
+const RULES: [Rule; 9] = [
+    Rule::HieuhDeletion,
+    Rule::Aspiration,
+    Rule::Palatalization,
+    Rule::Liaison,
+    Rule::Simplification,
+    Rule::Neutralization,
+    Rule::Lateralization,
+    Rule::Nasalization,
+    Rule::Tensification,
+];
+
+fn is_hieuh(jong: Jongseong) -> bool {
+    matches!(
+        jong,
+        Jongseong::Hieuh | Jongseong::NieunHieuh | Jongseong::RieulHieuh
+    )
+}
+
+/// The jongseong as the choseong of the next syllable.
+fn to_choseong(jong: Jongseong) -> Option<Choseong> {
+    Choseong::from_char(&jong.to_char())
+}
+
+fn aspirate(cho: Choseong) -> Option<Choseong> {
+    match cho {
+        Choseong::Giyeok => Some(Choseong::Kieuk),
+        Choseong::Digeut => Some(Choseong::Tieut),
+        Choseong::Bieup => Some(Choseong::Pieup),
+        Choseong::Jieut => Some(Choseong::Chieut),
+        _ => None,
+    }
+}
+
+fn is_stop(jong: Jongseong) -> bool {
+    matches!(
+        jong,
+        Jongseong::Giyeok | Jongseong::Digeut | Jongseong::Bieup
+    )
+}
+
+/// Applies a single rule to the boundary after a syllable, given the boundary as
+/// written and as changed by the rules before it, and the vowel after it.
+/// Returns the new boundary if the rule fired.
+fn apply(
+    rule: Rule,
+    syllable: (Choseong, Jungseong),
+    written: Boundary,
+    current: Boundary,
+    vowel: Jungseong,
+) -> Option<Boundary> {
+    let (jong, cho) = match current {
+        (Some(jong), cho) => (jong, cho),
+        (None, Some(cho)) => {
+            // ㄷ aspirated by ㅎ before 이 is still palatalized: 굳히다 -> 구치다
+            return match (rule, written, cho, vowel) {
+                (
+                    Rule::Palatalization,
+                    (Some(Jongseong::Digeut), Some(Choseong::Hieuh)),
+                    Choseong::Tieut,
+                    Jungseong::I,
+                ) => Some((None, Some(Choseong::Chieut))),
+                _ => None,
+            };
+        }
+        (None, None) => return None,
+    };
+    let rest = jong.split().map(|(first, _)| first);
+
+    match (rule, cho) {
+        (Rule::HieuhDeletion, Some(Choseong::Ieung)) if is_hieuh(jong) => {
+            Some((rest, Some(Choseong::Ieung)))
+        }
+        (Rule::HieuhDeletion, Some(Choseong::Siot)) if is_hieuh(jong) => {
+            Some((rest, Some(Choseong::SsangSiot)))
+        }
+
+        (Rule::Aspiration, Some(cho)) if is_hieuh(jong) => Some((rest, Some(aspirate(cho)?))),
+        (Rule::Aspiration, Some(Choseong::Hieuh)) => {
+            let (rest, last) = match jong.split() {
+                Some((first, second)) => (Some(first), second),
+                None => (None, jong),
+            };
+            let aspirated = match last {
+                Jongseong::Jieut => Choseong::Chieut,
+                last => aspirate(to_choseong(neutralize(last))?)?,
+            };
+            Some((rest, Some(aspirated)))
+        }
+
+        (Rule::Palatalization, Some(Choseong::Ieung)) if vowel == Jungseong::I => match jong {
+            Jongseong::Digeut => Some((None, Some(Choseong::Jieut))),
+            Jongseong::Tieut => Some((None, Some(Choseong::Chieut))),
+            Jongseong::RieulTieut => Some((Some(Jongseong::Rieul), Some(Choseong::Chieut))),
+            _ => None,
+        },
+
+        (Rule::Liaison, Some(Choseong::Ieung)) if jong != Jongseong::Ieung => match jong.split() {
+            Some((first, second)) => Some((Some(first), Some(to_choseong(second)?))),
+            None => Some((None, Some(to_choseong(jong)?))),
+        },
+
+        // 밟다 -> 밥따, 밟는 -> 밤는
+        (Rule::Simplification, Some(cho))
+            if jong == Jongseong::RieulBieup
+                && syllable == (Choseong::Bieup, Jungseong::A)
+                && cho != Choseong::Ieung =>
+        {
+            Some((Some(Jongseong::Bieup), Some(cho)))
+        }
+        (Rule::Simplification, cho) if jong.is_compound() && cho != Some(Choseong::Ieung) => {
+            Some((Some(neutralize(jong)), cho))
+        }
+        (Rule::Neutralization, cho) if neutralize(jong) != jong => {
+            Some((Some(neutralize(jong)), cho))
+        }
+
+        (Rule::Lateralization, Some(Choseong::Rieul)) if jong == Jongseong::Nieun => {
+            Some((Some(Jongseong::Rieul), Some(Choseong::Rieul)))
+        }
+        (Rule::Lateralization, Some(Choseong::Nieun)) if jong == Jongseong::Rieul => {
+            Some((Some(Jongseong::Rieul), Some(Choseong::Rieul)))
+        }
+
+        (Rule::Nasalization, Some(Choseong::Rieul)) if jong != Jongseong::Rieul => {
+            Some((Some(nasalize(jong)), Some(Choseong::Nieun)))
+        }
+        (Rule::Nasalization, Some(Choseong::Nieun | Choseong::Mieum)) if is_stop(jong) => {
+            Some((Some(nasalize(jong)), cho))
+        }
+
+        // after liaison, only a moved ㅅ is tense: 값이 -> 갑씨, but 닭이 -> 달기
+        (Rule::Tensification, Some(cho)) => {
+            let moved = written.1 == Some(Choseong::Ieung);
+            let after_stop = is_stop(jong) || written.0 == Some(Jongseong::RieulBieup);
+            match after_stop && (!moved || cho == Choseong::Siot) {
+                true => Some((Some(jong), Some(cho.double()?))),
+                false => None,
+            }
+        }
+
+        _ => None,
+    }
+}
+
+/// Pronounces a word, returning its jamo as pronounced and the changes made.
+/// Positions are counted from `offset`.
+pub(crate) fn pronounce_word(word: &[Jamo], offset: usize) -> (Vec<Jamo>, Vec<Change>) {
+    let mut pronounced = word.to_vec();
+    let mut changes = Vec::new();
+
+    for i in 0..pronounced.len() {
+        let next = pronounced.get(i + 1).map(|(cho, jung, _)| (*cho, *jung));
+        let written: Boundary = (pronounced[i].2, next.map(|(cho, _)| cho));
+        // the vowel only matters before a next syllable
+        let vowel = next.map_or(Jungseong::A, |(_, jung)| jung);
+        let mut current = written;
+
+        for rule in RULES.iter() {
+            let syllable = (pronounced[i].0, pronounced[i].1);
+            if let Some(after) = apply(*rule, syllable, written, current, vowel) {
+                changes.push(Change {
+                    position: offset + i,
+                    rule: *rule,
+                    before: current,
+                    after,
+                });
+                current = after;
+            }
+        }
+
+        pronounced[i].2 = current.0;
+        if let (Some(next), Some(cho)) = (pronounced.get_mut(i + 1), current.1) {
+            next.0 = cho;
+        }
+    }
+
+    (pronounced, changes)
+}
+
+fn pronounce_content(content: &str) -> (String, Vec<Change>) {
+    let mut pronounced = String::new();
+    let mut changes = Vec::new();
+    let mut offset = 0;
+
+    for (word, rest) in split_words(content) {
+        let (jamo, word_changes) = pronounce_word(&word, offset);
+        for (cho, jung, jong) in jamo {
+            let jong = jong.map(|jong| jong.to_char());
+            let syllable = compose_char(&cho.to_char(), &jung.to_char(), jong.as_ref())
+                .expect("pronounced jamo are composable");
+            pronounced.push(syllable);
+        }
+        pronounced.push_str(&rest);
+        changes.extend(word_changes);
+        offset += word.len() + rest.chars().count();
+    }
+
+    (pronounced, changes)
+}
+
+/// Writes the content as it is pronounced. Everything but Hangeul syllables is
+/// kept as it is.
+///
+/// ```rust
+/// use hangeul::pronunciation::pronounce;
+///
+/// assert_eq!("오시", pronounce("옷이"));
+/// assert_eq!("궁물", pronounce("국물"));
+/// assert_eq!("가치", pronounce("같이"));
+/// assert_eq!("학꾜", pronounce("학교"));
+/// assert_eq!("달기 닥", pronounce("닭이 닭"));
+/// ```
+pub fn pronounce(content: &str) -> String {
+    pronounce_content(content).0
+}
+
+/// The rules that fired when pronouncing the content, in order.
+///
+/// ```rust
+/// use hangeul::models::{Choseong, Jongseong};
+/// use hangeul::pronunciation::{changes, Rule};
+///
+/// let changes = changes("신라");
+/// assert_eq!(1, changes.len());
+/// assert_eq!(Rule::Lateralization, changes[0].rule);
+/// assert_eq!((Some(Jongseong::Nieun), Some(Choseong::Rieul)), changes[0].before);
+/// assert_eq!((Some(Jongseong::Rieul), Some(Choseong::Rieul)), changes[0].after);
+/// ```
+pub fn changes(content: &str) -> Vec<Change> {
+    pronounce_content(content).1
+}
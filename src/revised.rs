@@ -0,0 +1,709 @@
+//! The Revised Romanization of Korean (국어의 로마자 표기법), the official
+//! romanization of South Korea since 2000.
+//!
+//! Words are romanized as they are pronounced, so the sound changes between
+//! syllables are carried over: 신라 -> Silla, 종로 -> Jongno, 같이 -> gachi.
+//! Tensing (된소리) is not written, and neither are the changes that depend on
+//! where one word of a compound ends, such as the ㄴ added in 학여울 (Hangnyeoul).
+use crate::models::*;
+
+/// How text is romanized. The default romanizes by pronunciation, in lowercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Capitalizes every word, as is done for proper nouns: Seoul.
+    pub capitalize: bool,
+    /// Separates syllables with a hyphen where they could be split another
+    /// way: Jung-ang (중앙), not Jun-gang.
+    pub hyphenate: bool,
+    /// Transliterates letter by letter, ignoring pronunciation, so that the
+    /// Hangeul can be restored: 독립 -> doglib. Within a word, silent ㅇ is written
+    /// as a hyphen, and so are boundaries that would read as a double consonant.
+    pub strict: bool,
+}
+
+/// The jamo of a syllable.
+pub(crate) type Jamo = (Choseong, Jungseong, Option<Jongseong>);
+
+pub(crate) fn to_jamo(c: char) -> Option<Jamo> {
+    if !crate::is_syllable(c as u32) {
+        return None;
+    }
+
+    Some((
+        Choseong::from_char(&c)?,
+        Jungseong::from_char(&c)?,
+        Jongseong::from_char(&c),
+    ))
+}
+
+/// Splits the content into runs of Hangeul syllables and everything else, in order.
+pub(crate) fn split_words(content: &str) -> Vec<(Vec<Jamo>, String)> {
+    let mut parts: Vec<(Vec<Jamo>, String)> = vec![(Vec::new(), String::new())];
+
+    for c in content.chars() {
+        let last = parts.last_mut().expect("parts are never empty");
+        match to_jamo(c) {
+            Some(jamo) if last.1.is_empty() => last.0.push(jamo),
+            Some(jamo) => parts.push((vec![jamo], String::new())),
+            None => last.1.push(c),
+        }
+    }
+
+    parts
+}
+
+/// The consonant a jongseong is pronounced as at the end of a syllable (음절의
+/// 끝소리 규칙): one of ㄱ, ㄴ, ㄷ, ㄹ, ㅁ, ㅂ and ㅇ.
+pub(crate) fn neutralize(jong: Jongseong) -> Jongseong {
+    match jong {
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::Kieuk
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok => Jongseong::Giyeok,
+        Jongseong::Nieun | Jongseong::NieunJieut | Jongseong::NieunHieuh => Jongseong::Nieun,
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieuh => Jongseong::Digeut,
+        Jongseong::Rieul
+        | Jongseong::RieulBieup
+        | Jongseong::RieulSiot
+        | Jongseong::RieulTieut
+        | Jongseong::RieulHieuh => Jongseong::Rieul,
+        Jongseong::Mieum | Jongseong::RieulMieum => Jongseong::Mieum,
+        Jongseong::Bieup | Jongseong::Pieup | Jongseong::BieupSiot | Jongseong::RieulPieup => {
+            Jongseong::Bieup
+        }
+        Jongseong::Ieung => Jongseong::Ieung,
+    }
+}
+
+/// ㄱ, ㄷ and ㅂ become ㅇ, ㄴ and ㅁ before a nasal (비음화).
+pub(crate) fn nasalize(jong: Jongseong) -> Jongseong {
+    match jong {
+        Jongseong::Giyeok => Jongseong::Ieung,
+        Jongseong::Digeut => Jongseong::Nieun,
+        Jongseong::Bieup => Jongseong::Mieum,
+        other => other,
+    }
+}
+
+/// The jongseong pronounced as the choseong of the next syllable (연음), turning
+/// ㄷ and ㅌ into ㅈ and ㅊ before ㅣ (구개음화).
+fn link(jong: Jongseong, vowel: Jungseong) -> Choseong {
+    match (jong, vowel) {
+        (Jongseong::Digeut, Jungseong::I) => Choseong::Jieut,
+        (Jongseong::Tieut, Jungseong::I) => Choseong::Chieut,
+        (jong, _) => Choseong::from_char(&jong.to_char()).unwrap_or(Choseong::Ieung),
+    }
+}
+
+/// The sounds on both sides of a syllable boundary, as pronounced.
+fn boundary(jong: Jongseong, cho: Choseong, vowel: Jungseong) -> (Option<Jongseong>, Choseong) {
+    match (jong, cho) {
+        (Jongseong::Ieung, Choseong::Ieung) => (Some(jong), cho),
+        (Jongseong::Hieuh, Choseong::Ieung) => (None, cho),
+        (Jongseong::NieunHieuh, Choseong::Ieung) => (None, Choseong::Nieun),
+        (Jongseong::RieulHieuh, Choseong::Ieung) => (None, Choseong::Rieul),
+        (jong, Choseong::Ieung) => match jong.split() {
+            Some((first, second)) => (Some(neutralize(first)), link(second, vowel)),
+            None => (None, link(jong, vowel)),
+        },
+
+        // ㅎ aspirates the consonant after it
+        (Jongseong::Hieuh | Jongseong::NieunHieuh | Jongseong::RieulHieuh, cho) => {
+            let rest = jong.split().map(|(first, _)| first);
+            match cho {
+                Choseong::Giyeok => (rest, Choseong::Kieuk),
+                Choseong::Digeut => (rest, Choseong::Tieut),
+                Choseong::Jieut => (rest, Choseong::Chieut),
+                Choseong::Nieun if jong == Jongseong::RieulHieuh => (rest, Choseong::Rieul),
+                Choseong::Nieun => (Some(Jongseong::Nieun), cho),
+                _ => (rest, cho),
+            }
+        }
+        // ㅎ after ㄱ, ㄷ and ㅂ is kept, as in nouns (묵호 -> Mukho), but ㄷ before
+        // 히 still becomes ㅊ: 굳히다 -> guchida
+        (Jongseong::Digeut, Choseong::Hieuh) if vowel == Jungseong::I => (None, Choseong::Chieut),
+
+        (jong, cho) => {
+            let jong = neutralize(jong);
+            match (jong, cho) {
+                (Jongseong::Nieun | Jongseong::Rieul, Choseong::Rieul) => {
+                    (Some(Jongseong::Rieul), Choseong::Rieul)
+                }
+                (Jongseong::Rieul, Choseong::Nieun) => (Some(Jongseong::Rieul), Choseong::Rieul),
+                (jong, Choseong::Rieul) => (Some(nasalize(jong)), Choseong::Nieun),
+                (jong, Choseong::Nieun | Choseong::Mieum) => (Some(nasalize(jong)), cho),
+                (jong, cho) => (Some(jong), cho),
+            }
+        }
+    }
+}
+
+/// Applies the sound changes between the syllables of a word, leaving every
+/// jongseong as one of the seven final sounds.
+pub(crate) fn pronounce(word: &[Jamo]) -> Vec<Jamo> {
+    let mut pronounced = word.to_vec();
+
+    for i in 0..pronounced.len() {
+        let jong = match pronounced[i].2 {
+            Some(jong) => jong,
+            None => continue,
+        };
+
+        pronounced[i].2 = match pronounced.get(i + 1).copied() {
+            Some((cho, jung, _)) => {
+                let (jong, cho) = boundary(jong, cho, jung);
+                pronounced[i + 1].0 = cho;
+                jong
+            }
+            None => Some(neutralize(jong)),
+        };
+    }
+
+    pronounced
+}
+
+fn choseong(cho: Choseong) -> &'static str {
+    match cho {
+        Choseong::Giyeok => "g",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut => "d",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "r",
+        Choseong::Mieum => "m",
+        Choseong::Bieup => "b",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut => "j",
+        Choseong::SsangJieut => "jj",
+        Choseong::Chieut => "ch",
+        Choseong::Kieuk => "k",
+        Choseong::Tieut => "t",
+        Choseong::Pieup => "p",
+        Choseong::Hieuh => "h",
+    }
+}
+
+fn jungseong(jung: Jungseong) -> &'static str {
+    match jung {
+        Jungseong::A => "a",
+        Jungseong::AE => "ae",
+        Jungseong::YA => "ya",
+        Jungseong::YAE => "yae",
+        Jungseong::EO => "eo",
+        Jungseong::E => "e",
+        Jungseong::YEO => "yeo",
+        Jungseong::YE => "ye",
+        Jungseong::O => "o",
+        Jungseong::WA => "wa",
+        Jungseong::WAE => "wae",
+        Jungseong::OE => "oe",
+        Jungseong::YO => "yo",
+        Jungseong::U => "u",
+        Jungseong::WEO => "wo",
+        Jungseong::WE => "we",
+        Jungseong::WI => "wi",
+        Jungseong::YU => "yu",
+        Jungseong::EU => "eu",
+        Jungseong::YI => "ui",
+        Jungseong::I => "i",
+    }
+}
+
+/// The final sounds, after `neutralize`.
+fn final_sound(jong: Jongseong) -> &'static str {
+    match neutralize(jong) {
+        Jongseong::Giyeok => "k",
+        Jongseong::Nieun => "n",
+        Jongseong::Digeut => "t",
+        Jongseong::Rieul => "l",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p",
+        _ => "ng",
+    }
+}
+
+/// The letters of a jongseong, for strict transliteration.
+fn final_letters(jong: Jongseong) -> &'static str {
+    match jong {
+        Jongseong::Giyeok => "g",
+        Jongseong::SsangGiyeok => "kk",
+        Jongseong::GiyeokSiot => "gs",
+        Jongseong::Nieun => "n",
+        Jongseong::NieunJieut => "nj",
+        Jongseong::NieunHieuh => "nh",
+        Jongseong::Digeut => "d",
+        Jongseong::Rieul => "l",
+        Jongseong::RieulGiyeok => "lg",
+        Jongseong::RieulMieum => "lm",
+        Jongseong::RieulBieup => "lb",
+        Jongseong::RieulSiot => "ls",
+        Jongseong::RieulTieut => "lt",
+        Jongseong::RieulPieup => "lp",
+        Jongseong::RieulHieuh => "lh",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "b",
+        Jongseong::BieupSiot => "bs",
+        Jongseong::Siot => "s",
+        Jongseong::SsangSiot => "ss",
+        Jongseong::Ieung => "ng",
+        Jongseong::Jieut => "j",
+        Jongseong::Chieut => "ch",
+        Jongseong::Kieuk => "k",
+        Jongseong::Tieut => "t",
+        Jongseong::Pieup => "p",
+        Jongseong::Hieuh => "h",
+    }
+}
+
+/// Whether the letters on both sides of a boundary could be read across it.
+fn is_ambiguous(before: &str, after: &str) -> bool {
+    let (last, first) = match (before.chars().last(), after.chars().next()) {
+        (Some(last), Some(first)) => (last, first),
+        _ => return false,
+    };
+
+    match (last, first) {
+        ('g' | 'n', 'a' | 'e' | 'i' | 'o' | 'u') | ('n', 'g') => true,
+        _ => ["ae", "eo", "eu", "oe", "ui"].contains(&format!("{}{}", last, first).as_str()),
+    }
+}
+
+fn romanize_word(word: &[Jamo], options: Options) -> String {
+    let mut romanized = String::new();
+
+    if options.strict {
+        for (i, (cho, jung, jong)) in word.iter().enumerate() {
+            let initial = match cho {
+                Choseong::Rieul => "l",
+                cho => choseong(*cho),
+            };
+            // silent ㅇ, and letters that would read as a double consonant: gabs-sa
+            let doubled = romanized.chars().last().filter(|c| "kstpj".contains(*c));
+            if (i > 0 && initial.is_empty()) || doubled.is_some_and(|c| initial.starts_with(c)) {
+                romanized.push('-');
+            }
+            romanized.push_str(initial);
+            romanized.push_str(jungseong(*jung));
+            romanized.push_str(jong.map_or("", final_letters));
+        }
+        return romanized;
+    }
+
+    let mut previous: Option<Jongseong> = None;
+    for (cho, jung, jong) in pronounce(word) {
+        let mut syllable = match (previous, cho) {
+            (Some(Jongseong::Rieul), Choseong::Rieul) => "l".to_string(),
+            _ => choseong(cho).to_string(),
+        };
+        syllable.push_str(jungseong(jung));
+
+        if options.hyphenate && is_ambiguous(&romanized, &syllable) {
+            romanized.push('-');
+        }
+        romanized.push_str(&syllable);
+        romanized.push_str(jong.map_or("", final_sound));
+        previous = jong;
+    }
+
+    romanized
+}
+
+pub(crate) fn capitalize(content: &str) -> String {
+    let mut capitalized = String::with_capacity(content.len());
+    let mut at_start = true;
+
+    for c in content.chars() {
+        match at_start {
+            true => capitalized.extend(c.to_uppercase()),
+            false => capitalized.push(c),
+        }
+        at_start = c.is_whitespace();
+    }
+
+    capitalized
+}
+
+/// Romanizes the Hangeul in the content by pronunciation. Everything else is
+/// kept as it is.
+///
+/// ```rust
+/// use hangeul::revised::romanize;
+///
+/// assert_eq!("hangeul", romanize("한글"));
+/// assert_eq!("silla", romanize("신라"));
+/// assert_eq!("gachi", romanize("같이"));
+/// assert_eq!("baengma", romanize("백마"));
+/// ```
+pub fn romanize(content: &str) -> String {
+    romanize_with(content, Options::default())
+}
+
+/// Romanizes the Hangeul in the content. See `Options`.
+///
+/// ```rust
+/// use hangeul::revised::{romanize_with, Options};
+///
+/// let options = Options { capitalize: true, hyphenate: true, ..Options::default() };
+/// assert_eq!("Jung-ang", romanize_with("중앙", options));
+/// assert_eq!("Seoul Jongno", romanize_with("서울 종로", options));
+///
+/// let strict = Options { strict: true, ..Options::default() };
+/// assert_eq!("doglib", romanize_with("독립", strict));
+/// ```
+pub fn romanize_with(content: &str, options: Options) -> String {
+    let romanized: String = split_words(content)
+        .iter()
+        .map(|(word, rest)| romanize_word(word, options) + rest)
+        .collect();
+
+    match options.capitalize {
+        true => capitalize(&romanized),
+        false => romanized,
+    }
+}
+
+/// How many candidates are kept at each step of `to_hangeul`.
+const CANDIDATES: usize = 10;
+
+/// Romanized choseong, with how unlikely each reading is. Aspirated and tense
+/// consonants are less common than plain ones.
+const INITIALS: [(&str, Choseong, f64); 19] = [
+    ("kk", Choseong::SsangGiyeok, 0.3),
+    ("tt", Choseong::SsangDigeut, 0.3),
+    ("pp", Choseong::SsangBieup, 0.3),
+    ("ss", Choseong::SsangSiot, 0.3),
+    ("jj", Choseong::SsangJieut, 0.3),
+    ("ch", Choseong::Chieut, 0.3),
+    ("g", Choseong::Giyeok, 0.0),
+    ("k", Choseong::Kieuk, 0.3),
+    ("n", Choseong::Nieun, 0.0),
+    ("d", Choseong::Digeut, 0.0),
+    ("t", Choseong::Tieut, 0.3),
+    ("r", Choseong::Rieul, 0.0),
+    ("l", Choseong::Rieul, 0.1),
+    ("m", Choseong::Mieum, 0.0),
+    ("b", Choseong::Bieup, 0.0),
+    ("p", Choseong::Pieup, 0.3),
+    ("s", Choseong::Siot, 0.0),
+    ("j", Choseong::Jieut, 0.0),
+    ("h", Choseong::Hieuh, 0.0),
+];
+
+/// Spellings from before the Revised Romanization, still common at the start of a
+/// word: Kim, Pusan, Taegu, Cheju.
+const WORD_INITIALS: [(&str, Choseong, f64); 4] = [
+    ("k", Choseong::Giyeok, 0.2),
+    ("t", Choseong::Digeut, 0.2),
+    ("p", Choseong::Bieup, 0.2),
+    ("ch", Choseong::Jieut, 0.2),
+];
+
+const VOWELS: [(&str, Jungseong); 21] = [
+    ("a", Jungseong::A),
+    ("ae", Jungseong::AE),
+    ("ya", Jungseong::YA),
+    ("yae", Jungseong::YAE),
+    ("eo", Jungseong::EO),
+    ("e", Jungseong::E),
+    ("yeo", Jungseong::YEO),
+    ("ye", Jungseong::YE),
+    ("o", Jungseong::O),
+    ("wa", Jungseong::WA),
+    ("wae", Jungseong::WAE),
+    ("oe", Jungseong::OE),
+    ("yo", Jungseong::YO),
+    ("u", Jungseong::U),
+    ("wo", Jungseong::WEO),
+    ("we", Jungseong::WE),
+    ("wi", Jungseong::WI),
+    ("yu", Jungseong::YU),
+    ("eu", Jungseong::EU),
+    ("ui", Jungseong::YI),
+    ("i", Jungseong::I),
+];
+
+/// Romanized final sounds, with the jongseong each may have been written with.
+/// The last four are only written so by strict transliteration.
+const FINALS: [(&str, Jongseong, f64); 19] = [
+    ("ng", Jongseong::Ieung, 0.0),
+    ("k", Jongseong::Giyeok, 0.0),
+    ("k", Jongseong::Kieuk, 0.5),
+    ("k", Jongseong::SsangGiyeok, 0.5),
+    ("n", Jongseong::Nieun, 0.0),
+    ("t", Jongseong::Siot, 0.0),
+    ("t", Jongseong::Digeut, 0.2),
+    ("t", Jongseong::Jieut, 0.5),
+    ("t", Jongseong::Chieut, 0.5),
+    ("t", Jongseong::Tieut, 0.5),
+    ("t", Jongseong::Hieuh, 0.5),
+    ("l", Jongseong::Rieul, 0.0),
+    ("m", Jongseong::Mieum, 0.0),
+    ("p", Jongseong::Bieup, 0.0),
+    ("p", Jongseong::Pieup, 0.3),
+    ("g", Jongseong::Giyeok, 0.5),
+    ("b", Jongseong::Bieup, 0.5),
+    ("d", Jongseong::Digeut, 0.5),
+    ("s", Jongseong::Siot, 0.5),
+];
+
+/// Each syllable costs a little, so that fewer syllables are preferred: 서울, not 세오울.
+const SYLLABLE_COST: f64 = 0.1;
+
+/// Syllables starting with a vowel are less common within a word: 한글, not 항을.
+const SILENT_COST: f64 = 0.05;
+
+/// A consonant read as the choseong of the next syllable may have been the
+/// jongseong of the one before: hangugeo may be 한국어.
+const LINKING_COST: f64 = 0.6;
+
+/// Assimilated consonants may have been others before assimilation: jongno may be 종로.
+const ASSIMILATION_COST: f64 = 0.4;
+
+/// The sounds that assimilation leaves at a boundary, and what may have been
+/// written instead.
+const ASSIMILATED: [((Jongseong, Choseong), (Jongseong, Choseong)); 12] = [
+    (
+        (Jongseong::Rieul, Choseong::Rieul),
+        (Jongseong::Nieun, Choseong::Rieul),
+    ),
+    (
+        (Jongseong::Rieul, Choseong::Rieul),
+        (Jongseong::Rieul, Choseong::Nieun),
+    ),
+    (
+        (Jongseong::Ieung, Choseong::Nieun),
+        (Jongseong::Ieung, Choseong::Rieul),
+    ),
+    (
+        (Jongseong::Ieung, Choseong::Nieun),
+        (Jongseong::Giyeok, Choseong::Nieun),
+    ),
+    (
+        (Jongseong::Ieung, Choseong::Nieun),
+        (Jongseong::Giyeok, Choseong::Rieul),
+    ),
+    (
+        (Jongseong::Ieung, Choseong::Mieum),
+        (Jongseong::Giyeok, Choseong::Mieum),
+    ),
+    (
+        (Jongseong::Nieun, Choseong::Nieun),
+        (Jongseong::Digeut, Choseong::Nieun),
+    ),
+    (
+        (Jongseong::Nieun, Choseong::Mieum),
+        (Jongseong::Digeut, Choseong::Mieum),
+    ),
+    (
+        (Jongseong::Mieum, Choseong::Nieun),
+        (Jongseong::Mieum, Choseong::Rieul),
+    ),
+    (
+        (Jongseong::Mieum, Choseong::Nieun),
+        (Jongseong::Bieup, Choseong::Nieun),
+    ),
+    (
+        (Jongseong::Mieum, Choseong::Nieun),
+        (Jongseong::Bieup, Choseong::Rieul),
+    ),
+    (
+        (Jongseong::Mieum, Choseong::Mieum),
+        (Jongseong::Bieup, Choseong::Mieum),
+    ),
+];
+
+type Candidate = (f64, Vec<Jamo>);
+
+fn keep_best<T>(candidates: &mut Vec<(f64, T)>) {
+    candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("costs are never NaN"));
+    candidates.truncate(CANDIDATES);
+}
+
+/// Reads the letters from the given position on as syllables.
+fn parse_syllables(
+    letters: &str,
+    start: usize,
+    memo: &mut Vec<Option<Vec<Candidate>>>,
+) -> Vec<Candidate> {
+    if start == letters.len() {
+        return vec![(0.0, Vec::new())];
+    }
+    if let Some(parsed) = &memo[start] {
+        return parsed.clone();
+    }
+
+    let rest = &letters[start..];
+    let (word_initials, silent_cost) = match start {
+        0 => (&WORD_INITIALS[..], 0.0),
+        _ => (&[][..], SILENT_COST),
+    };
+    let initials = INITIALS
+        .iter()
+        .chain(word_initials.iter())
+        .filter(|(written, _, _)| rest.starts_with(written))
+        .map(|(written, cho, cost)| (written.len(), *cho, *cost))
+        .chain(std::iter::once((0, Choseong::Ieung, silent_cost)));
+
+    let mut parsed = Vec::new();
+    for (initial_len, cho, initial_cost) in initials {
+        let after_initial = &rest[initial_len..];
+
+        for (vowel, jung) in VOWELS
+            .iter()
+            .filter(|(vowel, _)| after_initial.starts_with(vowel))
+        {
+            let end = start + initial_len + vowel.len();
+            let finals = FINALS
+                .iter()
+                .filter(|(written, _, _)| letters[end..].starts_with(written))
+                .map(|(written, jong, cost)| (written.len(), Some(*jong), *cost))
+                .chain(std::iter::once((0, None, 0.0)));
+
+            for (final_len, jong, final_cost) in finals {
+                for (rest_cost, rest) in parse_syllables(letters, end + final_len, memo) {
+                    let linked = match (jong, rest.first()) {
+                        (Some(jong), Some((Choseong::Ieung, _, _))) if jong != Jongseong::Ieung => {
+                            LINKING_COST
+                        }
+                        _ => 0.0,
+                    };
+
+                    let mut syllables = vec![(cho, *jung, jong)];
+                    syllables.extend(rest);
+                    let cost = initial_cost + final_cost + linked + SYLLABLE_COST + rest_cost;
+                    parsed.push((cost, syllables));
+                }
+            }
+        }
+    }
+    keep_best(&mut parsed);
+
+    memo[start] = Some(parsed.clone());
+    parsed
+}
+
+/// Adds the spellings that assimilation may have hidden. A spelling is only
+/// added if `romanize` gives it the same romanization as the reading it came
+/// from.
+fn unassimilate(candidates: &mut Vec<Candidate>) {
+    let mut variants = Vec::new();
+
+    for (cost, syllables) in candidates.iter() {
+        let romanized = romanize_word(syllables, Options::default());
+        let mut found: Vec<Candidate> = vec![(*cost, syllables.clone())];
+
+        for i in 1..syllables.len() {
+            let sounds = match (syllables[i - 1].2, syllables[i].0) {
+                (Some(jong), cho) => (jong, cho),
+                _ => continue,
+            };
+
+            let mut more = Vec::new();
+            for (_, after) in ASSIMILATED.iter().filter(|(before, _)| *before == sounds) {
+                for (cost, syllables) in found.iter() {
+                    let mut syllables = syllables.clone();
+                    syllables[i - 1].2 = Some(after.0);
+                    syllables[i].0 = after.1;
+                    if romanize_word(&syllables, Options::default()) == romanized {
+                        more.push((cost + ASSIMILATION_COST, syllables));
+                    }
+                }
+            }
+            found.extend(more);
+            keep_best(&mut found);
+        }
+        variants.extend(found.into_iter().filter(|(_, found)| found != syllables));
+    }
+
+    candidates.extend(variants);
+}
+
+fn compose_syllables(syllables: &[Jamo]) -> String {
+    syllables
+        .iter()
+        .filter_map(|(cho, jung, jong)| {
+            crate::compose_char(
+                &cho.to_char(),
+                &jung.to_char(),
+                jong.map(|j| j.to_char()).as_ref(),
+            )
+            .ok()
+        })
+        .collect()
+}
+
+/// Reads romanized text back into Hangeul. Romanization loses information, so
+/// candidates are returned from the most likely to the least. Hyphens separate
+/// syllables, letters that can't be read are kept as they are, and so is
+/// everything else.
+///
+/// ```rust
+/// use hangeul::revised::to_hangeul;
+///
+/// assert_eq!("강남", to_hangeul("gangnam")[0]);
+/// assert_eq!("부산", to_hangeul("Busan")[0]);
+/// assert_eq!("김치", to_hangeul("kimchi")[0]);
+/// assert_eq!("중앙", to_hangeul("jung-ang")[0]);
+/// assert!(to_hangeul("jongno").contains(&"종로".to_string()));
+/// ```
+pub fn to_hangeul(romanized: &str) -> Vec<String> {
+    let lowercase = romanized.to_lowercase();
+    let mut results: Vec<(f64, String)> = vec![(0.0, String::new())];
+
+    let mut rest = lowercase.as_str();
+    while !rest.is_empty() {
+        let letters_end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let (letters, after) = rest.split_at(letters_end);
+
+        let part: Vec<(f64, String)> = if letters.is_empty() {
+            let c = after.chars().next().expect("rest is not empty");
+            rest = &after[c.len_utf8()..];
+            match c {
+                '-' | '\'' => vec![(0.0, String::new())],
+                c => vec![(0.0, c.to_string())],
+            }
+        } else {
+            rest = after;
+            let mut memo = vec![None; letters.len()];
+            let mut syllables = parse_syllables(letters, 0, &mut memo);
+            unassimilate(&mut syllables);
+
+            match syllables.is_empty() {
+                true => vec![(0.0, letters.to_string())],
+                false => syllables
+                    .iter()
+                    .map(|(cost, syllables)| (*cost, compose_syllables(syllables)))
+                    .collect(),
+            }
+        };
+
+        let mut combined = Vec::new();
+        for (cost, text) in results.iter() {
+            for (part_cost, part_text) in part.iter() {
+                combined.push((cost + part_cost, format!("{}{}", text, part_text)));
+            }
+        }
+        keep_best(&mut combined);
+        results = combined;
+    }
+
+    let mut candidates: Vec<String> = Vec::new();
+    for (_, text) in results {
+        if !candidates.contains(&text) {
+            candidates.push(text);
+        }
+    }
+    candidates
+}
@@ -0,0 +1,203 @@
+//! Search by initial consonants (초성 검색), as used for contact lists: ㅎㄱ finds
+//! 한글.
+//!
+//! A compatibility consonant in the query matches any syllable that starts with
+//! it, and every other char matches itself, so queries can mix the two: 한ㄱ.
+//! Latin letters are matched regardless of case.
+//!
+//! While a query is being typed its last syllable may still change, so
+//! incremental matching also lets it match the syllables it could become: 한그
+//! finds 한글, and 고 finds 과. Its jongseong may also turn out to start the next
+//! syllable (도깨비불), so 간 finds 가나 as well.
+use crate::get_choseong;
+use crate::is_compat_jamo;
+use crate::is_syllable;
+use crate::models::*;
+
+/// Where in the candidate the query has to match.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// At the start: ㅎㄱ finds 한국, but not 대한국.
+    #[default]
+    Prefix,
+    /// Anywhere, in consecutive chars: ㅎㄱ finds 대한국.
+    Substring,
+    /// Anywhere, in order, with other chars in between: ㅎㄱ finds 한조국.
+    Subsequence,
+}
+
+/// How a query is matched. The default matches prefixes, syllable by syllable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    pub mode: Mode,
+    /// Takes the last syllable of the query as still being typed, so that it
+    /// matches every syllable it could become by typing on: 하 matches 한 and 할,
+    /// 고 matches 과, and 갈 matches 갉. When it has a jongseong, it also matches
+    /// the two syllables a vowel typed after it would give: 간 matches 가나, and
+    /// 닭 matches 달가.
+    pub incremental: bool,
+}
+
+fn matches_char(query: char, candidate: char) -> bool {
+    let code = query as u32;
+    if is_compat_jamo(code) && Choseong::from_jamo(code).is_some() {
+        return get_choseong(&candidate) == Ok(query);
+    }
+
+    query.to_lowercase().eq(candidate.to_lowercase())
+}
+
+/// Checks if typing on after the query syllable could give the candidate one:
+/// a jongseong can be added to it, and its jungseong or jongseong extended into
+/// a compound.
+fn could_become(query: char, candidate: char) -> bool {
+    if !is_syllable(query as u32) || !is_syllable(candidate as u32) {
+        return false;
+    }
+    let jamo = |c: char| {
+        (
+            Choseong::from_char(&c),
+            Jungseong::from_char(&c),
+            Jongseong::from_char(&c),
+        )
+    };
+    let (query_cho, query_jung, query_jong) = jamo(query);
+    let (cho, jung, jong) = jamo(candidate);
+
+    if query_cho != cho {
+        return false;
+    }
+    match query_jong {
+        // 고 -> 과, 곽
+        None => jung == query_jung || jung.and_then(|j| j.split()).map(|(j, _)| j) == query_jung,
+        // 갈 -> 갉
+        Some(query_jong) => {
+            jung == query_jung
+                && (jong == Some(query_jong)
+                    || jong.and_then(|j| j.split()).map(|(j, _)| j) == Some(query_jong))
+        }
+    }
+}
+
+/// Checks if typing a vowel after the query syllable could give the two
+/// candidate ones, its jongseong (or the last consonant of a compound one)
+/// moving over to start the second: 간 -> 가나, 닭 -> 달가.
+fn could_split(query: char, first: char, second: char) -> bool {
+    if !is_syllable(query as u32) || !is_syllable(first as u32) || !is_syllable(second as u32) {
+        return false;
+    }
+    let (query_jong, first_jong) = match Jongseong::from_char(&query) {
+        Some(jong) => match jong.split() {
+            Some((rest, moved)) => (moved, Some(rest)),
+            None => (jong, None),
+        },
+        None => return false,
+    };
+
+    Choseong::from_char(&query) == Choseong::from_char(&first)
+        && Jungseong::from_char(&query) == Jungseong::from_char(&first)
+        && Jongseong::from_char(&first) == first_jong
+        && Choseong::from_char(&second) == Choseong::from_char(&query_jong.to_char())
+}
+
+/// Joins the matched positions into spans of consecutive chars.
+fn spans(positions: &[usize]) -> Vec<(usize, usize)> {
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+
+    for &position in positions {
+        match spans.last_mut() {
+            Some(span) if span.1 == position => span.1 += 1,
+            _ => spans.push((position, position + 1)),
+        }
+    }
+
+    spans
+}
+
+/// Finds the query in the candidate, returning the spans of the candidate that
+/// were matched, for highlighting. A span is the char position of its start and
+/// of the char after its end. Returns `None` if the query doesn't match.
+///
+/// ```rust
+/// use hangeul::search::{find, Mode, Options};
+///
+/// assert_eq!(Some(vec![(0, 2)]), find("ㅎㄱ", "한글", Options::default()));
+/// assert_eq!(Some(vec![(0, 2)]), find("한ㄱ", "한글", Options::default()));
+/// assert_eq!(None, find("ㄱㅎ", "한글", Options::default()));
+///
+/// let subsequence = Options { mode: Mode::Subsequence, ..Options::default() };
+/// assert_eq!(Some(vec![(0, 1), (2, 3)]), find("ㅎㄷ", "홍길동", subsequence));
+///
+/// let incremental = Options { incremental: true, ..Options::default() };
+/// assert_eq!(Some(vec![(0, 2)]), find("한그", "한글", incremental));
+/// assert_eq!(Some(vec![(0, 2)]), find("간", "가나다", incremental));
+/// ```
+pub fn find(query: &str, candidate: &str, options: Options) -> Option<Vec<(usize, usize)>> {
+    let query: Vec<char> = query.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    if query.len() > candidate.len() {
+        return None;
+    }
+    if query.is_empty() {
+        return Some(Vec::new());
+    }
+
+    // the last char of the query may match two chars of the candidate, so it
+    // gives how many it matched
+    let last = query.len() - 1;
+    let matches_last = |position: usize| {
+        let c = *candidate.get(position)?;
+        if matches_char(query[last], c) || (options.incremental && could_become(query[last], c)) {
+            return Some(1);
+        }
+        let next = *candidate.get(position + 1)?;
+        match options.incremental && could_split(query[last], c, next) {
+            true => Some(2),
+            false => None,
+        }
+    };
+    let matches_at = |start: usize| {
+        let matched = (0..last).all(|i| matches_char(query[i], candidate[start + i]));
+        match matched {
+            true => matches_last(start + last).map(|length| last + length),
+            false => None,
+        }
+    };
+
+    let positions: Vec<usize> = match options.mode {
+        Mode::Prefix => (0..matches_at(0)?).collect(),
+        Mode::Substring => {
+            let (start, length) = (0..=candidate.len() - query.len())
+                .find_map(|start| matches_at(start).map(|length| (start, length)))?;
+            (start..start + length).collect()
+        }
+        Mode::Subsequence => {
+            let mut positions = Vec::with_capacity(query.len() + 1);
+            let mut rest = 0..candidate.len();
+            for q in &query[..last] {
+                let position = rest.find(|position| matches_char(*q, candidate[*position]))?;
+                positions.push(position);
+            }
+            let (position, length) =
+                rest.find_map(|position| matches_last(position).map(|length| (position, length)))?;
+            positions.extend(position..position + length);
+            positions
+        }
+    };
+
+    Some(spans(&positions))
+}
+
+/// Checks if the query matches the candidate. See `find`.
+///
+/// ```rust
+/// use hangeul::search::{is_match, Mode, Options};
+///
+/// let substring = Options { mode: Mode::Substring, ..Options::default() };
+/// assert!(is_match("ㄷㅎㅁㄱ", "대한민국", substring));
+/// assert!(is_match("ㅁㄱ", "대한민국", substring));
+/// assert!(!is_match("ㅁㄱ", "대한민국", Options::default()));
+/// ```
+pub fn is_match(query: &str, candidate: &str, options: Options) -> bool {
+    find(query, candidate, options).is_some()
+}
@@ -0,0 +1,259 @@
+//! Reversible transliteration, writing the letters of Hangeul rather than its
+//! sounds, so that every syllable can be read back.
+//!
+//! A separator is only written between two syllables that would otherwise be
+//! read differently: Yale writes 익히 as ik.hi, since ikhi would be 이키.
+use crate::constants::*;
+use crate::is_syllable;
+
+/// A transliteration system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum System {
+    /// Yale romanization, the system of Korean linguistics. ㅓ is e, ㅡ is u,
+    /// ㅜ is wu and ㅈ is c: 한국어 -> hankwuk.e. Ambiguous boundaries are
+    /// separated with a period.
+    Yale,
+    /// ISO/TR 11941, method 1 (South Korea). ㅊ is c and ㅢ is yi: 한국어 ->
+    /// hangug-eo. Ambiguous boundaries are separated with a hyphen.
+    Iso11941,
+}
+
+struct Tables {
+    /// Choseong, in Unicode order
+    initials: [&'static str; 19],
+    /// Jungseong, in Unicode order
+    vowels: [&'static str; 21],
+    /// Jongseong, in Unicode order after the empty one
+    finals: [&'static str; 28],
+    separator: char,
+}
+
+const YALE: Tables = Tables {
+    initials: [
+        "k", "kk", "n", "t", "tt", "l", "m", "p", "pp", "s", "ss", "", "c", "cc", "ch", "kh", "th",
+        "ph", "h",
+    ],
+    vowels: [
+        "a", "ay", "ya", "yay", "e", "ey", "ye", "yey", "o", "wa", "way", "oy", "yo", "wu", "we",
+        "wey", "wi", "yu", "u", "uy", "i",
+    ],
+    finals: [
+        "", "k", "kk", "ks", "n", "nc", "nh", "t", "l", "lk", "lm", "lp", "ls", "lth", "lph", "lh",
+        "m", "p", "ps", "s", "ss", "ng", "c", "ch", "kh", "th", "ph", "h",
+    ],
+    separator: '.',
+};
+
+const ISO_11941: Tables = Tables {
+    initials: [
+        "g", "gg", "n", "d", "dd", "r", "m", "b", "bb", "s", "ss", "", "j", "jj", "c", "k", "t",
+        "p", "h",
+    ],
+    vowels: [
+        "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "weo",
+        "we", "wi", "yu", "eu", "yi", "i",
+    ],
+    finals: [
+        "", "g", "gg", "gs", "n", "nj", "nh", "d", "l", "lg", "lm", "lb", "ls", "lt", "lp", "lh",
+        "m", "b", "bs", "s", "ss", "ng", "j", "c", "k", "t", "p", "h",
+    ],
+    separator: '-',
+};
+
+/// Indices of the choseong, jungseong and jongseong (0 for none) of a syllable.
+type Indices = (usize, usize, usize);
+
+fn is_vowel_letter(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'w' | 'y')
+}
+
+impl System {
+    fn tables(&self) -> &'static Tables {
+        match self {
+            System::Yale => &YALE,
+            System::Iso11941 => &ISO_11941,
+        }
+    }
+
+    /// Reads a run of letters without separators. Vowels are read longest first,
+    /// and consonants between vowels go to the second syllable where they can.
+    fn parse(&self, letters: &str) -> Option<Vec<Indices>> {
+        let tables = self.tables();
+        let position = |list: &[&str], written: &str| list.iter().position(|s| *s == written);
+
+        // alternating runs of consonants and vowels, starting with consonants
+        let mut runs: Vec<&str> = Vec::new();
+        let mut start = 0;
+        let mut in_vowels = false;
+        for (i, c) in letters.char_indices() {
+            if is_vowel_letter(c) != in_vowels {
+                runs.push(&letters[start..i]);
+                start = i;
+                in_vowels = !in_vowels;
+            }
+        }
+        runs.push(&letters[start..]);
+        if runs.len() % 2 == 0 {
+            runs.push("");
+        }
+
+        let mut syllables: Vec<Indices> = Vec::new();
+        let mut initial = position(&tables.initials, runs[0])?;
+        let pairs: Vec<&[&str]> = runs[1..].chunks(2).collect();
+
+        for (n, pair) in pairs.iter().enumerate() {
+            let (mut vowels, consonants) = (pair[0], pair[1]);
+
+            while !vowels.is_empty() {
+                let (vowel, len) = (1..=vowels.len().min(3))
+                    .rev()
+                    .find_map(|len| position(&tables.vowels, &vowels[..len]).map(|v| (v, len)))?;
+                syllables.push((initial, vowel, 0));
+                initial = position(&tables.initials, "")?;
+                vowels = &vowels[len..];
+            }
+
+            let last = syllables.last_mut()?;
+            if n == pairs.len() - 1 {
+                last.2 = position(&tables.finals, consonants)?;
+                continue;
+            }
+            let split = (0..=consonants.len()).find(|i| {
+                position(&tables.finals, &consonants[..*i]).is_some()
+                    && position(&tables.initials, &consonants[*i..]).is_some()
+            })?;
+            last.2 = position(&tables.finals, &consonants[..split])?;
+            initial = position(&tables.initials, &consonants[split..])?;
+        }
+
+        Some(syllables)
+    }
+
+    /// Reads a word, separators included.
+    fn read(&self, word: &str) -> Option<Vec<Indices>> {
+        let mut syllables = Vec::new();
+        for part in word.split(self.tables().separator) {
+            syllables.extend(self.parse(part)?);
+        }
+        Some(syllables)
+    }
+
+    fn render(&self, (initial, vowel, last): Indices) -> String {
+        let tables = self.tables();
+        format!(
+            "{}{}{}",
+            tables.initials[initial], tables.vowels[vowel], tables.finals[last]
+        )
+    }
+
+    fn transliterate_word(&self, word: &[Indices]) -> String {
+        let mut written = String::new();
+
+        for (i, syllable) in word.iter().enumerate() {
+            let joined = format!("{}{}", written, self.render(*syllable));
+            if i == 0 || self.read(&joined).as_deref() == Some(&word[..=i]) {
+                written = joined;
+            } else {
+                written.push(self.tables().separator);
+                written.push_str(&self.render(*syllable));
+            }
+        }
+
+        written
+    }
+}
+
+fn indices(c: char) -> Option<Indices> {
+    let code = c as u32;
+    if !is_syllable(code) {
+        return None;
+    }
+
+    let index = code - HANGEUL_OFFSET;
+    Some((
+        (index / CHOSEONG_COUNT) as usize,
+        (index % CHOSEONG_COUNT / JUNGSEONG_COUNT) as usize,
+        (index % JUNGSEONG_COUNT) as usize,
+    ))
+}
+
+fn syllable((initial, vowel, last): Indices) -> char {
+    let code = HANGEUL_OFFSET
+        + initial as u32 * CHOSEONG_COUNT
+        + vowel as u32 * JUNGSEONG_COUNT
+        + last as u32;
+    std::char::from_u32(code).expect("indices come from the tables")
+}
+
+/// Transliterates the Hangeul in the content. Everything else is kept as it is.
+///
+/// ```rust
+/// use hangeul::transliteration::{transliterate, System};
+///
+/// assert_eq!("hankwuk.e", transliterate("한국어", System::Yale));
+/// assert_eq!("ik.hi", transliterate("익히", System::Yale));
+/// assert_eq!("hangug-eo", transliterate("한국어", System::Iso11941));
+/// ```
+pub fn transliterate(content: &str, system: System) -> String {
+    let mut transliterated = String::new();
+    let mut word = Vec::new();
+
+    for c in content.chars() {
+        match indices(c) {
+            Some(syllable) => word.push(syllable),
+            None => {
+                transliterated.push_str(&system.transliterate_word(&word));
+                word.clear();
+                transliterated.push(c);
+            }
+        }
+    }
+    transliterated.push_str(&system.transliterate_word(&word));
+
+    transliterated
+}
+
+/// Reads transliterated text back into Hangeul. Runs of letters that can't be
+/// read are kept as they are, and so is everything else.
+///
+/// ```rust
+/// use hangeul::transliteration::{to_hangeul, System};
+///
+/// assert_eq!("한국어", to_hangeul("hankwuk.e", System::Yale));
+/// assert_eq!("익히", to_hangeul("ik.hi", System::Yale));
+/// assert_eq!("한국어 사전", to_hangeul("hangug-eo sajeon", System::Iso11941));
+/// ```
+pub fn to_hangeul(content: &str, system: System) -> String {
+    let separator = system.tables().separator;
+    let chars: Vec<char> = content.chars().collect();
+    let mut hangeul = String::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_alphabetic() {
+            hangeul.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        // a word runs on over separators between letters
+        let start = i;
+        while i < chars.len() {
+            let c = chars[i];
+            let is_separator =
+                c == separator && chars.get(i + 1).is_some_and(|c| c.is_ascii_alphabetic());
+            if !c.is_ascii_alphabetic() && !is_separator {
+                break;
+            }
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+
+        match system.read(&word.to_ascii_lowercase()) {
+            Some(syllables) => hangeul.extend(syllables.into_iter().map(syllable)),
+            None => hangeul.push_str(&word),
+        }
+    }
+
+    hangeul
+}
@@ -0,0 +1,78 @@
+extern crate hangeul;
+use hangeul::amount::*;
+use hangeul::errors::HangeulError;
+
+#[test]
+fn check_format() {
+    let cases = [
+        (0, "금 영원정", "金 零圓整", "0원"),
+        (10, "금 일십원정", "金 壹拾圓整", "10원"),
+        (1_000, "금 일천원정", "金 壹仟圓整", "1,000원"),
+        (10_000, "금 일만원정", "金 壹萬圓整", "1만 원"),
+        (
+            1_234_000,
+            "금 일백이십삼만사천원정",
+            "金 壹佰貳拾參萬肆仟圓整",
+            "123만 4,000원",
+        ),
+        (
+            100_050_001,
+            "금 일억오만일원정",
+            "金 壹億伍萬壹圓整",
+            "1억 5만 1원",
+        ),
+        (
+            123_450_000,
+            "금 일억이천삼백사십오만원정",
+            "金 壹億貳仟參佰肆拾伍萬圓整",
+            "1억 2,345만 원",
+        ),
+    ];
+
+    for (amount, formal, hanja, mixed) in cases.iter() {
+        assert_eq!(*formal, format(*amount, Style::Formal));
+        assert_eq!(*hanja, format(*amount, Style::Hanja));
+        assert_eq!(*mixed, format(*amount, Style::Mixed));
+    }
+}
+
+#[test]
+fn check_parse_roundtrip() {
+    let styles = [Style::Formal, Style::Hanja, Style::Mixed];
+
+    for amount in [
+        0,
+        7,
+        10_010,
+        99_999,
+        1_000_000,
+        987_654_321_012,
+        u32::MAX as u64,
+        i64::MAX as u64,
+        i64::MAX as u64 + 1,
+        u64::MAX,
+    ] {
+        for style in styles.iter() {
+            assert_eq!(Ok(amount), parse(&format(amount, *style)));
+        }
+    }
+}
+
+#[test]
+fn check_parse() {
+    assert_eq!(Ok(15_000), parse("만 오천 원"));
+    assert_eq!(Ok(1_000_000), parse("金 一百萬圓整"));
+    assert_eq!(Ok(50_000), parse("5만원"));
+    assert_eq!(Err(HangeulError::InvalidNumber(4)), parse("금 원정"));
+    assert_eq!(Err(HangeulError::InvalidNumber(5)), parse("금 오만 달러"));
+    assert_eq!(Err(HangeulError::OutOfRange), parse("마이너스 만 원"));
+    assert_eq!(Err(HangeulError::OutOfRange), parse("금 이천경원정"));
+}
+
+#[test]
+fn check_validate() {
+    assert_eq!(Ok(true), validate("金 壹佰貳拾參萬肆仟圓整", 1_234_000));
+    assert_eq!(Ok(false), validate("金 壹佰貳拾參萬肆仟圓整", 1_234_001));
+    assert_eq!(Ok(true), validate("1억 2,345만 원", 123_450_000));
+    assert!(validate("금 일백 원정 원정", 100).is_err());
+}
@@ -0,0 +1,73 @@
+#![allow(clippy::bool_assert_comparison)]
+
+extern crate hangeul;
+use hangeul::archaic::*;
+use hangeul::models::OldJamo;
+
+#[test]
+fn check_old_jamo() {
+    assert_eq!(Some(OldJamo::AraeA), OldJamo::from_char(&'ㆍ'));
+    assert_eq!(Some(OldJamo::AraeA), OldJamo::from_char(&'\u{119E}'));
+    assert_eq!(Some(OldJamo::Bansiot), OldJamo::from_char(&'\u{11EB}'));
+    assert_eq!(Some(OldJamo::Yeorinhieuh), OldJamo::from_char(&'ᅙ'));
+    assert_eq!(None, OldJamo::from_char(&'ㄱ'));
+    assert_eq!('ㆁ', OldJamo::Yesieung.to_char());
+    assert!(OldJamo::AraeA.is_vowel());
+}
+
+#[test]
+fn check_classification() {
+    assert_eq!(is_old_jamo(0x1113), true);
+    assert_eq!(is_old_jamo(0x11FF), true);
+    assert_eq!(is_old_jamo(0xD7FB), true);
+    assert_eq!(is_old_jamo(0xD7FC), false);
+    assert_eq!(is_old_jamo(0x1112), false);
+    assert_eq!(is_old_jamo(0x115F), false);
+    assert_eq!(position(0x115F), Some(Position::Choseong));
+    assert_eq!(position(0x1160), Some(Position::Jungseong));
+    assert_eq!(position(0xA97C), Some(Position::Choseong));
+    assert_eq!(position(0xD7C6), Some(Position::Jungseong));
+    assert_eq!(position(0xD7CB), Some(Position::Jongseong));
+    assert_eq!(position(0xD7C7), None);
+}
+
+#[test]
+fn check_modern_apis_unchanged() {
+    assert_eq!(hangeul::is_choseong('ᅀ' as u32), false);
+    assert_eq!(hangeul::is_jungseong('ㆍ' as u32), false);
+    assert_eq!(hangeul::is_hangeul(0xD7B0), false);
+    assert_eq!(is_hangeul(0xD7B0), true);
+    assert!(hangeul::get_choseong(&'ᅀ').is_err());
+}
+
+#[test]
+fn check_syllables() {
+    // 세종어제 훈민정음: 나랏말ᄊᆞ미 듕귁에 달아
+    let text = "나랏말ᄊᆞ미 듕귁에";
+    assert_eq!(
+        vec!["나", "랏", "말", "ᄊᆞ", "미", " ", "듕", "귁", "에"],
+        syllables(text)
+    );
+    assert_eq!(vec!["가\u{11EB}"], syllables("가\u{11EB}"));
+    assert_eq!(
+        vec!["\u{1100}\u{1100}\u{1161}"],
+        syllables("\u{1100}\u{1100}\u{1161}")
+    );
+}
+
+#[test]
+fn check_normalize() {
+    assert_eq!("\u{1100}\u{1161}\u{11EB}", normalize("가\u{11EB}"));
+    assert_eq!(
+        "\u{1112}\u{119E}\u{11AB}",
+        normalize("\u{1112}\u{119E}\u{11AB}")
+    );
+    assert_eq!("\u{1100}\u{1160}", normalize("\u{1100}"));
+    assert_eq!("\u{115F}\u{1160}\u{11A8}", normalize("\u{11A8}"));
+    assert_eq!(
+        "한글",
+        normalize("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}")
+    );
+    assert_eq!("ok 한", normalize("ok 한"));
+    assert_eq!(Some('\u{1159}'), compat_to_conjoining(&'ㆆ'));
+}
@@ -0,0 +1,63 @@
+extern crate hangeul;
+use hangeul::autocorrect::*;
+
+#[test]
+fn check_detect_hangeul() {
+    for (typed, intended) in [
+        ("dkssud", "안녕"),
+        ("gksrmf", "한글"),
+        ("rlacl", "김치"),
+        ("tkfkdgo", "사랑해"),
+    ]
+    .iter()
+    {
+        let correction = detect(typed).expect(typed);
+        assert_eq!(*intended, correction.corrected);
+        assert_eq!(Intended::Hangeul, correction.intended);
+        assert!(correction.score >= THRESHOLD && correction.score <= 1.0);
+    }
+}
+
+#[test]
+fn check_detect_latin() {
+    for (typed, intended) in [
+        ("ㅗ디ㅣㅐ", "hello"),
+        ("ㅈㅐㄱㅣㅇ", "world"),
+        ("ㄱㅕㄴㅅ", "rust"),
+    ]
+    .iter()
+    {
+        let correction = detect(typed).expect(typed);
+        assert_eq!(*intended, correction.corrected);
+        assert_eq!(Intended::Latin, correction.intended);
+    }
+}
+
+#[test]
+fn check_detect_nothing() {
+    for word in [
+        "hello",
+        "go",
+        "the",
+        "안녕하세요",
+        "ㅋㅋㅋ",
+        "",
+        "a1",
+        "한글bus",
+        "dkssudx",
+    ]
+    .iter()
+    {
+        assert_eq!(None, detect(word), "{}", word);
+    }
+}
+
+#[test]
+fn check_suggest_and_correct() {
+    let corrections = suggest("ahrwjrwl: tjdnf (Seoul)");
+    let corrected: Vec<&str> = corrections.iter().map(|c| c.corrected.as_str()).collect();
+    assert_eq!(vec!["목적지", "서울"], corrected);
+
+    assert_eq!("목적지: 서울 (Seoul)", correct("ahrwjrwl: tjdnf (Seoul)"));
+    assert_eq!("  hello,  세상 ", correct("  ㅗ디ㅣㅐ,  세상 "));
+}
@@ -120,3 +120,71 @@ fn check_position() {
     assert_eq!(get_middle(&gam).unwrap(), 'ㅏ');
     assert_eq!(get_tail(&gam).unwrap(), 'ㅁ');
 }
+
+#[test]
+fn check_compose_jamo() {
+    assert_eq!(compose_jamo(&'ㅗ', &'ㅐ', false), Ok('ㅙ'));
+    assert_eq!(compose_jamo(&'ㅜ', &'ㅓ', false), Ok('ㅝ'));
+    assert_eq!(compose_jamo(&'ㄱ', &'ㅅ', false), Ok('ㄳ'));
+    assert_eq!(compose_jamo(&'ㅂ', &'ㅅ', false), Ok('ㅄ'));
+    assert_eq!(compose_jamo(&'ㅈ', &'ㅈ', true), Ok('ㅉ'));
+    assert_eq!(
+        compose_jamo(&'ㄹ', &'ㄹ', true),
+        Err(errors::HangeulError::Uncomposable)
+    );
+    assert_eq!(
+        compose_jamo(&'ㄱ', &'ㅏ', true),
+        Err(errors::HangeulError::Uncomposable)
+    );
+    assert_eq!(
+        compose_jamo(&'가', &'ㅅ', false),
+        Err(errors::HangeulError::Uncomposable)
+    );
+}
+
+#[test]
+fn check_compound_roundtrip() {
+    use hangeul::constants::*;
+
+    for code in COMPAT_CHOSEONG_START..=COMPAT_JUNGSEONG_END {
+        let jamo = std::char::from_u32(code).unwrap();
+        if let Ok((first, second)) = decompose_jamo(&jamo, true) {
+            assert_eq!(Ok(jamo), compose_jamo(&first, &second, true));
+        }
+    }
+    assert_eq!(decompose_jamo(&'ㄳ', false), Ok(('ㄱ', 'ㅅ')));
+    assert_eq!(decompose_jamo(&'ㄸ', true), Ok(('ㄷ', 'ㄷ')));
+    assert_eq!(
+        decompose_jamo(&'한', true),
+        Err(errors::HangeulError::NotACompound)
+    );
+}
+
+#[test]
+fn check_decompose_to_jamo() {
+    assert_eq!(
+        decompose_to_jamo("대한민국", false),
+        "ㄷㅐㅎㅏㄴㅁㅣㄴㄱㅜㄱ"
+    );
+    assert_eq!(decompose_to_jamo("읽기 ㄺ", false), "ㅇㅣㄺㄱㅣ ㄺ");
+    assert_eq!(decompose_to_jamo("읽기 ㄺ", true), "ㅇㅣㄹㄱㄱㅣ ㄹㄱ");
+    assert_eq!(decompose_to_jamo("뭐", true), "ㅁㅜㅓ");
+    assert_eq!(decompose_to_jamo("hello", true), "hello");
+}
+
+#[test]
+fn check_compose() {
+    assert_eq!(compose("ㄱㅏㄴㅏ"), "가나");
+    assert_eq!(compose("ㄱㅏㄴㄷㅏ"), "간다");
+    assert_eq!(compose("ㄷㅏㄹㄱㅇㅣ ㄷㅏㄺ"), "닭이 닭");
+    assert_eq!(compose("ㅇㅘㅇㅜㅓ"), "와워");
+    assert_eq!(compose("ㅋㅋ"), "ㅋㅋ");
+    assert_eq!(compose("ㄱㅏㅄㅣ"), "갑시");
+    assert_eq!(compose("ㄷㅏㄺㅇㅣ ㄷㅏㄺ ㄺ"), "닭이 닭 ㄺ");
+    assert_eq!(compose("abc"), "abc");
+
+    for text in &["대한민국", "읽었습니다", "괜찮아요, 뭐!", "값이 없어요"] {
+        assert_eq!(compose(&decompose_to_jamo(text, true)), *text);
+        assert_eq!(compose(&decompose_to_jamo(text, false)), *text);
+    }
+}
@@ -0,0 +1,120 @@
+extern crate hangeul;
+use hangeul::ime::*;
+use hangeul::keyboard::Layout;
+
+fn type_jamo(ic: &mut InputContext, jamo: &str) -> String {
+    let mut committed = String::new();
+    for c in jamo.chars() {
+        committed.push_str(&ic.process(c).commit);
+    }
+    committed
+}
+
+#[test]
+fn check_dokkaebibul() {
+    let mut ic = InputContext::new();
+    assert_eq!("하", type_jamo(&mut ic, "ㅎㅏㄴㅏ"));
+    assert_eq!(Some('나'), ic.preedit());
+
+    let mut ic = InputContext::new();
+    assert_eq!("달", type_jamo(&mut ic, "ㄷㅏㄹㄱㅣ"));
+    assert_eq!(Some('기'), ic.preedit());
+}
+
+#[test]
+fn check_compound_jamo() {
+    let mut ic = InputContext::new();
+    assert_eq!("", type_jamo(&mut ic, "ㄷㅏㄹㄱ"));
+    assert_eq!(Some('닭'), ic.preedit());
+
+    let mut ic = InputContext::new();
+    assert_eq!("", type_jamo(&mut ic, "ㄱㅗㅏㄴ"));
+    assert_eq!(Some('관'), ic.preedit());
+}
+
+#[test]
+fn check_sentence() {
+    let mut ic = InputContext::new();
+    let mut text = type_jamo(&mut ic, "ㅇㅏㄴㄴㅕㅇㅎㅏㅅㅔㅇㅛ. ㄱㅡㄹ");
+    text.push_str(&ic.flush());
+    assert_eq!("안녕하세요. 글", text);
+    assert!(ic.is_empty());
+}
+
+#[test]
+fn check_unattachable_jamo() {
+    let mut ic = InputContext::new();
+    // ㅓ can't follow ㅗ, ㄸ can't be a jongseong
+    assert_eq!("고", type_jamo(&mut ic, "ㄱㅗㅓ"));
+    assert_eq!(Some('ㅓ'), ic.preedit());
+    assert_eq!("ㅓ", type_jamo(&mut ic, "ㄸ"));
+    assert_eq!("따", type_jamo(&mut ic, "ㅏㄸ"));
+    assert_eq!(Some('ㄸ'), ic.preedit());
+}
+
+#[test]
+fn check_backspace() {
+    let mut ic = InputContext::new();
+    type_jamo(&mut ic, "ㄱㅗㅏㄴ");
+    let mut preedits = vec![];
+    while ic.backspace() {
+        preedits.push(ic.preedit());
+    }
+    assert_eq!(vec![Some('과'), Some('고'), Some('ㄱ'), None], preedits);
+}
+
+fn type_keys(layout: Layout, keys: &str) -> String {
+    let mut ic = InputContext::with_layout(layout);
+    let mut text = String::new();
+    for key in keys.chars() {
+        text.push_str(&ic.process_key(key).commit);
+    }
+    text.push_str(&ic.flush());
+    text
+}
+
+#[test]
+fn check_dubeolsik_keys() {
+    assert_eq!("한글", type_keys(Layout::Dubeolsik, "gksrmf"));
+    assert_eq!("빨리", type_keys(Layout::Dubeolsik, "QKffl"));
+}
+
+#[test]
+fn check_sebeolsik_390() {
+    assert_eq!("한글", type_keys(Layout::Sebeolsik390, "mfskgw"));
+    // compound vowels, both ㅗ keys
+    assert_eq!("과과", type_keys(Layout::Sebeolsik390, "kvfk/f"));
+    // no 도깨비불: a jongseong stays where it was typed
+    assert_eq!("안아", type_keys(Layout::Sebeolsik390, "jfsjf"));
+    // compound jongseong, typed in two strokes or with shift
+    assert_eq!("닭닭", type_keys(Layout::Sebeolsik390, "ufwxufD"));
+    // doubled choseong
+    assert_eq!("까", type_keys(Layout::Sebeolsik390, "kkf"));
+    assert_eq!("1", type_keys(Layout::Sebeolsik390, "M"));
+}
+
+#[test]
+fn check_sebeolsik_final() {
+    assert_eq!("닭값", type_keys(Layout::SebeolsikFinal, "uf@kfX"));
+    assert_eq!("얘기", type_keys(Layout::SebeolsikFinal, "jGkd"));
+}
+
+#[test]
+fn check_sebeolsik_noshift() {
+    assert_eq!("닭값", type_keys(Layout::SebeolsikNoshift, "ufwxkf3q"));
+    assert_eq!("밖", type_keys(Layout::SebeolsikNoshift, ";fxx"));
+    assert_eq!("꽃", type_keys(Layout::SebeolsikNoshift, "kkv="));
+}
+
+#[test]
+fn check_sebeolsik_backspace() {
+    let mut ic = InputContext::with_layout(Layout::Sebeolsik390);
+    "ufwx".chars().for_each(|key| {
+        ic.process_key(key);
+    });
+    assert_eq!(Some('닭'), ic.preedit());
+    assert!(ic.backspace());
+    assert_eq!(Some('달'), ic.preedit());
+    assert!(ic.backspace());
+    assert_eq!(Some('다'), ic.preedit());
+}
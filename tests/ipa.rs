@@ -0,0 +1,81 @@
+extern crate hangeul;
+use hangeul::ipa::*;
+
+#[test]
+fn check_transcribe() {
+    let cases = [
+        ("한국어", "han.ɡu.ɡʌ"),
+        ("한글", "han.ɡɯl"),
+        ("서울", "sʌ.ul"),
+        ("김치", "kim.tɕʰi"),
+        ("의사", "ɰi.sa"),
+        ("희망", "hi.maŋ"),
+        ("시계", "ɕi.ɡe"),
+        ("쉬다", "ɕɥi.da"),
+        ("외국", "ø.ɡuk̚"),
+        ("좋아요", "tɕo.a.jo"),
+    ];
+
+    for (hangeul, ipa) in cases.iter() {
+        assert_eq!(*ipa, transcribe(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_allophones() {
+    let cases = [
+        // lax, tense and aspirated stops
+        ("가다", "ka.da"),
+        ("까다", "k͈a.da"),
+        ("카드", "kʰa.dɯ"),
+        ("바보", "pa.bo"),
+        ("자주", "tɕa.dʑu"),
+        ("싸다", "s͈a.da"),
+        ("씨", "ɕ͈i"),
+        // ㄹ as a flap and as a lateral
+        ("다리", "ta.ɾi"),
+        ("달", "tal"),
+        ("빨리", "p͈al.li"),
+        ("신라", "ɕil.la"),
+        // ㅎ between voiced sounds
+        ("전화", "tɕʌn.ɦwa"),
+        // unreleased finals and sound changes
+        ("밥", "pap̚"),
+        ("옷", "ot̚"),
+        ("국물", "kuŋ.mul"),
+        ("학교", "hak̚.k͈jo"),
+        ("같이", "ka.tɕʰi"),
+        ("좋고", "tɕo.kʰo"),
+    ];
+
+    for (hangeul, ipa) in cases.iter() {
+        assert_eq!(*ipa, transcribe(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_phones() {
+    assert_eq!(
+        vec![
+            Phone::Consonant("k"),
+            Phone::Glide("w"),
+            Phone::Vowel("a"),
+            Phone::Consonant("ŋ"),
+            Phone::WordBreak,
+            Phone::Consonant("t͈"),
+            Phone::Vowel("a"),
+            Phone::Consonant("l"),
+        ],
+        phones("광, 딸!")
+    );
+    assert!(phones("hello").is_empty());
+
+    let formatted: String = phones("한국어").iter().map(Phone::to_string).collect();
+    assert_eq!(transcribe("한국어"), formatted);
+}
+
+#[test]
+fn check_mixed_content() {
+    assert_eq!("[han.ɡu.ɡʌ] 2024", transcribe("[한국어] 2024"));
+    assert_eq!("ha.na, tul", transcribe("하나, 둘"));
+}
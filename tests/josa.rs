@@ -0,0 +1,167 @@
+extern crate hangeul;
+use hangeul::errors::HangeulError;
+use hangeul::josa::*;
+
+#[test]
+fn check_consonant_and_vowel_forms() {
+    let cases = [
+        ("사과", Josa::EulReul, "사과를"),
+        ("귤", Josa::EulReul, "귤을"),
+        ("나", Josa::EunNeun, "나는"),
+        ("책", Josa::EunNeun, "책은"),
+        ("친구", Josa::GwaWa, "친구와"),
+        ("선생님", Josa::GwaWa, "선생님과"),
+        ("철수", Josa::AYa, "철수야"),
+        ("영민", Josa::AYa, "영민아"),
+        ("그대", Josa::IyeoYeo, "그대여"),
+        ("조국", Josa::IyeoYeo, "조국이여"),
+        ("의자", Josa::IeyoYeyo, "의자예요"),
+        ("책상", Josa::IeyoYeyo, "책상이에요"),
+        ("커피", Josa::INa, "커피나"),
+        ("빵", Josa::IRang, "빵이랑"),
+    ];
+
+    for (word, josa, expected) in cases.iter() {
+        assert_eq!(Ok(expected.to_string()), attach(word, *josa));
+    }
+}
+
+#[test]
+fn check_euro_ro() {
+    assert_eq!(Ok("서울로".to_string()), attach("서울", Josa::EuroRo));
+    assert_eq!(Ok("연필로".to_string()), attach("연필", Josa::EuroRo));
+    assert_eq!(Ok("부산으로".to_string()), attach("부산", Josa::EuroRo));
+    assert_eq!(Ok("학교로".to_string()), attach("학교", Josa::EuroRo));
+    // ㄹ clusters are not ㄹ
+    assert_eq!(Ok("닭으로".to_string()), attach("닭", Josa::EuroRo));
+}
+
+#[test]
+fn check_unknown_final_sound() {
+    assert_eq!(Err(HangeulError::UnknownFinalSound), attach("", Josa::IGa));
+    assert_eq!(
+        Err(HangeulError::UnknownFinalSound),
+        attach("한글!", Josa::IGa)
+    );
+    assert_eq!(
+        Err(HangeulError::UnknownFinalSound),
+        attach("ㅋ", Josa::IGa)
+    );
+}
+
+#[test]
+fn check_numbers() {
+    let cases = [
+        ("3", "3을"),
+        ("2", "2를"),
+        ("10", "10을"),
+        ("100", "100을"),
+        ("1,000", "1,000을"),
+        ("20000", "20000을"),
+        ("0", "0을"),
+        ("3.14", "3.14를"),
+        ("Windows 10", "Windows 10을"),
+        ("Python3", "Python3을"),
+        ("iPhone 15", "iPhone 15를"),
+    ];
+
+    for (word, expected) in cases.iter() {
+        assert_eq!(Ok(expected.to_string()), attach(word, Josa::EulReul));
+    }
+    assert_eq!(Ok("1로".to_string()), attach("1", Josa::EuroRo));
+    assert_eq!(Ok("6으로".to_string()), attach("6", Josa::EuroRo));
+}
+
+#[test]
+fn check_latin() {
+    let cases = [
+        ("SQL", "SQL을"),
+        ("PC", "PC를"),
+        ("LTE", "LTE를"),
+        ("IBM", "IBM을"),
+        ("Apple", "Apple을"),
+        ("Google", "Google을"),
+        ("iPhone", "iPhone을"),
+        ("internet", "internet을"),
+        ("Facebook", "Facebook을"),
+        ("app", "app을"),
+        ("computer", "computer를"),
+        ("test", "test를"),
+        ("Windows", "Windows를"),
+    ];
+
+    for (word, expected) in cases.iter() {
+        assert_eq!(Ok(expected.to_string()), attach(word, Josa::EulReul));
+    }
+    assert_eq!(Ok("URL로".to_string()), attach("URL", Josa::EuroRo));
+}
+
+#[test]
+fn check_symbols_and_brackets() {
+    assert_eq!(Ok("50%를".to_string()), attach("50%", Josa::EulReul));
+    assert_eq!(Ok("₩을".to_string()), attach("₩", Josa::EulReul));
+    assert_eq!(
+        Ok("한글(韓㐎)을".to_string()),
+        attach("한글(韓㐎)", Josa::EulReul)
+    );
+    assert_eq!(
+        Ok("\"사과\"를".to_string()),
+        attach("\"사과\"", Josa::EulReul)
+    );
+    assert_eq!(
+        Ok("'책(冊)'을".to_string()),
+        attach("'책(冊)'", Josa::EulReul)
+    );
+    assert_eq!(Ok("(주)는".to_string()), attach("(주)", Josa::EunNeun));
+    assert_eq!(
+        Err(HangeulError::UnknownFinalSound),
+        attach("韓", Josa::IGa)
+    );
+    assert_eq!(
+        Err(HangeulError::UnknownFinalSound),
+        attach("()", Josa::IGa)
+    );
+}
+
+#[test]
+fn check_ends_with_jongseong() {
+    assert_eq!(Ok(true), hangeul::ends_with_jongseong("3"));
+    assert_eq!(Ok(false), hangeul::ends_with_jongseong("2"));
+    assert_eq!(Ok(true), hangeul::ends_with_jongseong("Apple"));
+    assert_eq!(
+        Err(HangeulError::UnknownFinalSound),
+        hangeul::ends_with_jongseong("")
+    );
+}
+
+#[test]
+fn check_josa_ext() {
+    assert_eq!("사과를", format!("{}", "사과".josa(Josa::EulReul)));
+    assert_eq!("1,000원으로", format!("{}", "1,000원".josa(Josa::EuroRo)));
+    assert_eq!(
+        "SQL과",
+        format!("{}", String::from("SQL").josa(Josa::GwaWa))
+    );
+    assert_eq!("韓이(가)", format!("{}", "韓".josa(Josa::IGa)));
+}
+
+#[test]
+fn check_format() {
+    let args: [(&str, &dyn std::fmt::Display); 2] = [("a", &"커피"), ("b", &"빵")];
+    assert_eq!("커피와 빵", format("{a}와(과) {b}", &args));
+    assert_eq!("빵과 커피", format("{b}와(과) {a}", &args));
+    assert_eq!("빵이나 커피나", format("{b}(이)나 {a}(이)나", &args));
+    assert_eq!("커피는 빵은", format("{a}는(은) {b}은(는)", &args));
+    assert_eq!("빵이에요", format("{b}이에요(예요)", &args));
+    assert_eq!("커피예요", format("{a}(이)에요", &args));
+}
+
+#[test]
+fn check_format_leaves_the_rest() {
+    let args: [(&str, &dyn std::fmt::Display); 1] = [("a", &"커피")];
+    // patterns only follow placeholders
+    assert_eq!("책을(를) 커피를", format("책을(를) {a}을(를)", &args));
+    assert_eq!("{b}이(가)", format("{b}이(가)", &args));
+    assert_eq!("{a} 커피 }", format("{{a}} {a} }", &args));
+    assert_eq!("韓이(가)", format("{a}이(가)", &[("a", &"韓")]));
+}
@@ -0,0 +1,61 @@
+extern crate hangeul;
+use hangeul::keyboard::*;
+
+#[test]
+fn check_qwerty_to_hangeul() {
+    assert_eq!("안녕하세요", qwerty_to_hangeul("dkssudgktpdy"));
+    assert_eq!("대한민국", qwerty_to_hangeul("eogksalsrnr"));
+    assert_eq!("", qwerty_to_hangeul(""));
+}
+
+#[test]
+fn check_qwerty_to_hangeul_shift() {
+    assert_eq!("빨간 꽃", qwerty_to_hangeul("QKfrks Rhc"));
+    assert_eq!("얘기했어", qwerty_to_hangeul("dOrlgoTdj"));
+    assert_eq!("예쁘다", qwerty_to_hangeul("dPQmek"));
+}
+
+#[test]
+fn check_qwerty_to_hangeul_compounds() {
+    assert_eq!("닭값", qwerty_to_hangeul("ekfrrkqt"));
+    assert_eq!("괜찮아", qwerty_to_hangeul("rhoscksgdk"));
+    assert_eq!("읽어", qwerty_to_hangeul("dlfrdj"));
+    assert_eq!("의자", qwerty_to_hangeul("dmlwk"));
+}
+
+#[test]
+fn check_qwerty_to_hangeul_passthrough() {
+    assert_eq!("한글 2.0 (β)", qwerty_to_hangeul("gksrmf 2.0 (β)"));
+    assert_eq!("ㅋㅋㅋ", qwerty_to_hangeul("zzz"));
+}
+
+#[test]
+fn check_hangeul_to_qwerty() {
+    assert_eq!("dkssudgktpdy", hangeul_to_qwerty("안녕하세요"));
+    assert_eq!("rhoscksgdk", hangeul_to_qwerty("괜찮아"));
+    assert_eq!("dOrlgoTdj", hangeul_to_qwerty("얘기했어"));
+    assert_eq!("zz", hangeul_to_qwerty("ㅋㅋ"));
+    assert_eq!("abc 123", hangeul_to_qwerty("abc 123"));
+}
+
+#[test]
+fn check_qwerty_roundtrip() {
+    for text in [
+        "안녕하세요",
+        "닭값",
+        "읽었습니다",
+        "꽃밭에서 뛰놀아요",
+        "의외로 쉬웠다",
+    ]
+    .iter()
+    {
+        assert_eq!(*text, qwerty_to_hangeul(&hangeul_to_qwerty(text)));
+    }
+}
+
+#[test]
+fn check_count_keystrokes() {
+    assert_eq!(12, count_keystrokes("안녕하세요"));
+    assert_eq!(4, count_keystrokes("꽃"));
+    assert_eq!(0, count_keystrokes(""));
+}
@@ -0,0 +1,73 @@
+extern crate hangeul;
+use hangeul::kontsevich::*;
+
+#[test]
+fn check_transliterate() {
+    let cases = [
+        ("부산", "пусан"),
+        ("대구", "тэгу"),
+        ("인천", "инчхон"),
+        ("광주", "кванджу"),
+        ("제주", "чеджу"),
+        ("경주", "кёнджу"),
+        ("울산", "ульсан"),
+        ("김치", "кимчхи"),
+        ("한글", "хангыль"),
+        ("의자", "ыйджа"),
+        ("희망", "химан"),
+        ("떡", "тток"),
+        ("서울", "соуль"),
+    ];
+
+    for (hangeul, cyrillic) in cases.iter() {
+        assert_eq!(*cyrillic, transliterate(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_positional_consonants() {
+    let cases = [
+        ("고기", "коги"),
+        ("라면", "рамён"),
+        ("나라", "нара"),
+        ("달", "таль"),
+        ("신라", "силла"),
+        ("종로", "чонно"),
+        ("학교", "хаккё"),
+        ("칠곡", "чхильгок"),
+        ("평양", "пхёнъян"),
+        ("중앙", "чунъан"),
+        ("같이", "качхи"),
+    ];
+
+    for (hangeul, cyrillic) in cases.iter() {
+        assert_eq!(*cyrillic, transliterate(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_options() {
+    let names = Options {
+        capitalize: true,
+        traditional: true,
+    };
+    assert_eq!("Сеул", transliterate_with("서울", names));
+    assert_eq!("Пхеньян", transliterate_with("평양", names));
+    assert_eq!("Ли Сунсин", transliterate_with("이 순신", names));
+    assert_eq!("Ли Мальсун", transliterate_with("리 말순", names));
+    assert_eq!("Ким И", transliterate_with("김 이", names));
+    assert_eq!("Ким Ёна", transliterate_with("김 연아", names));
+
+    let capitalized = Options {
+        capitalize: true,
+        ..Options::default()
+    };
+    assert_eq!("Соуль", transliterate_with("서울", capitalized));
+    assert_eq!("Соуль, 2024", transliterate_with("서울, 2024", capitalized));
+    let traditional = Options {
+        traditional: true,
+        ..Options::default()
+    };
+    assert_eq!("(ли сунсин)", transliterate_with("(이 순신)", traditional));
+    assert_eq!("сеул-ёк", transliterate_with("서울-역", traditional));
+}
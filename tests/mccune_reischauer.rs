@@ -0,0 +1,59 @@
+extern crate hangeul;
+use hangeul::mccune_reischauer::*;
+
+#[test]
+fn check_romanize() {
+    let cases = [
+        ("부산", "pusan"),
+        ("대구", "taegu"),
+        ("김치", "kimch'i"),
+        ("경주", "kyŏngju"),
+        ("서울", "sŏul"),
+        ("조선", "chosŏn"),
+        ("금강산", "kŭmgangsan"),
+        ("의정부", "ŭijŏngbu"),
+        ("쉬다", "shwida"),
+        ("떡", "ttŏk"),
+        ("짜다", "tchada"),
+    ];
+
+    for (hangeul, romanized) in cases.iter() {
+        assert_eq!(*romanized, romanize(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_voicing_and_sound_changes() {
+    let cases = [
+        ("학교", "hakkyo"),
+        ("압구정", "apkujŏng"),
+        ("칠곡", "ch'ilgok"),
+        ("한강", "han'gang"),
+        ("중앙", "chung'ang"),
+        ("신라", "silla"),
+        ("종로", "chongno"),
+        ("독립문", "tongnimmun"),
+        ("백마", "paengma"),
+        ("같이", "kach'i"),
+        ("묵호", "mukho"),
+    ];
+
+    for (hangeul, romanized) in cases.iter() {
+        assert_eq!(*romanized, romanize(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_romanize_ascii() {
+    assert_eq!("seoul", romanize_ascii("서울"));
+    assert_eq!("euijeongbu", romanize_ascii("의정부"));
+    assert_eq!("kyeongju, 2024", romanize_ascii("경주, 2024"));
+    assert!(romanize_ascii("평양직할시 대동강구역").is_ascii());
+}
+
+#[test]
+fn check_separation() {
+    assert_eq!("p'yŏngyang", romanize("평양"));
+    assert_eq!("kangwŏn", romanize("강원"));
+    assert_eq!("kang'i", romanize("강이"));
+}
@@ -0,0 +1,83 @@
+extern crate hangeul;
+use hangeul::normalize::*;
+
+fn parse_column(column: &str) -> String {
+    column
+        .split_whitespace()
+        .map(|code| std::char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn check_normalization_test_data() {
+    let data = include_str!("data/hangul_normalization.txt");
+
+    for line in data.lines().filter(|l| !l.starts_with('#')) {
+        let columns: Vec<String> = line.split(';').take(5).map(parse_column).collect();
+        let (source, nfc, nfd) = (&columns[0], &columns[1], &columns[2]);
+
+        assert_eq!(*nfc, to_nfc(source), "NFC of {}", line);
+        assert_eq!(*nfc, to_nfc(nfc), "NFC of {}", line);
+        assert_eq!(*nfc, to_nfc(nfd), "NFC of {}", line);
+        assert_eq!(*nfd, to_nfd(source), "NFD of {}", line);
+        assert_eq!(*nfd, to_nfd(nfc), "NFD of {}", line);
+    }
+}
+
+#[test]
+fn check_nfd_roundtrip() {
+    use hangeul::constants::*;
+
+    for code in SYLLABLE_START..=SYLLABLE_END {
+        let syllable = std::char::from_u32(code).unwrap().to_string();
+        let nfd = to_nfd(&syllable);
+        assert!(nfd.chars().count() == 2 || nfd.chars().count() == 3);
+        assert_eq!(syllable, to_nfc(&nfd));
+    }
+}
+
+#[test]
+fn check_mixed_text() {
+    let nfc = "파일 이름.txt";
+    let nfd = to_nfd(nfc);
+    assert_ne!(nfc, nfd);
+    assert_eq!(nfc, to_nfc(&nfd));
+    assert_eq!("ㄱㅏ", to_nfc("ㄱㅏ"));
+}
+
+#[test]
+fn check_fold() {
+    assert_eq!(fold("㈜한글"), fold("(주)한글"));
+    assert_eq!("(오전) 10시", fold("㈝ 10시"));
+    assert_eq!("참고: 우편", fold("㉼: ㉾편"));
+    assert_eq!("ㄳ ㅘ", fold("\u{FFA3} \u{FFCD}"));
+    assert_eq!("\u{3164}", fold("\u{FFA0}"));
+    assert_eq!("plain 텍스트", fold("plain 텍스트"));
+}
+
+#[test]
+fn check_fold_covers_blocks() {
+    let halfwidth = (0xFFA1..=0xFFDC).filter_map(std::char::from_u32);
+    let circled = (0x3260..=0x327E).filter_map(std::char::from_u32);
+    let parenthesized = (0x3200..=0x321E).filter_map(std::char::from_u32);
+
+    for c in halfwidth.chain(circled).chain(parenthesized) {
+        if let Some(folded) = fold_char(&c) {
+            assert!(folded
+                .chars()
+                .filter(|f| *f != '(' && *f != ')')
+                .all(|f| hangeul::is_syllable(f as u32) || hangeul::is_compat_jamo(f as u32)));
+            assert!(is_hangeul(c as u32));
+        }
+    }
+}
+
+#[test]
+fn check_folded_classification() {
+    assert!(is_jaeum('ﾡ' as u32));
+    assert!(is_jaeum('ㄱ' as u32));
+    assert!(!is_jaeum('ￂ' as u32));
+    assert!(is_moeum('ￂ' as u32));
+    assert!(!is_moeum('㉮' as u32));
+    assert!(!is_hangeul('㉿' as u32));
+}
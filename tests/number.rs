@@ -0,0 +1,168 @@
+extern crate hangeul;
+use hangeul::errors::HangeulError;
+use hangeul::number::*;
+
+#[test]
+fn check_sino() {
+    let cases = [
+        (1, "일"),
+        (10, "십"),
+        (11, "십일"),
+        (105, "백오"),
+        (1000, "천"),
+        (2020, "이천이십"),
+        (10000, "만"),
+        (10001, "만 일"),
+        (110000, "십일만"),
+        (10_000_000, "천만"),
+        (100_000_000, "일억"),
+        (100_010_000, "일억 만"),
+        (300_000_000_000, "삼천억"),
+        (1_000_000_000_000, "일조"),
+        (10_000_000_000_000_000, "일경"),
+        (
+            i64::MAX,
+            "구백이십이경 삼천삼백칠십이조 삼백육십팔억 오천사백칠십칠만 오천팔백칠",
+        ),
+    ];
+
+    for (n, expected) in cases.iter() {
+        assert_eq!(*expected, sino(*n));
+    }
+    assert_eq!(
+        "마이너스 구백이십이경 삼천삼백칠십이조 삼백육십팔억 오천사백칠십칠만 오천팔백팔",
+        sino(i64::MIN)
+    );
+}
+
+#[test]
+fn check_sino_decimal() {
+    let cases = [
+        (12345.67, "만 이천삼백사십오 점 육칠"),
+        (-0.05, "마이너스 영 점 영오"),
+        (0.0, "영"),
+        (1e18, "백경"),
+    ];
+
+    for (n, read) in cases.iter() {
+        assert_eq!(Ok(read.to_string()), sino_decimal(*n), "{}", n);
+    }
+
+    for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e19, -1e21] {
+        assert_eq!(Err(HangeulError::OutOfRange), sino_decimal(n), "{}", n);
+    }
+}
+
+#[test]
+fn check_native() {
+    let cases = [
+        (1, "하나", "한"),
+        (2, "둘", "두"),
+        (3, "셋", "세"),
+        (4, "넷", "네"),
+        (5, "다섯", "다섯"),
+        (10, "열", "열"),
+        (11, "열하나", "열한"),
+        (20, "스물", "스무"),
+        (21, "스물하나", "스물한"),
+        (99, "아흔아홉", "아흔아홉"),
+    ];
+
+    for (n, cardinal, attributive) in cases.iter() {
+        assert_eq!(Ok(cardinal.to_string()), native(*n, false));
+        assert_eq!(Ok(attributive.to_string()), native(*n, true));
+    }
+    assert_eq!(Err(HangeulError::OutOfRange), native(0, false));
+    assert_eq!(Err(HangeulError::OutOfRange), native(100, true));
+}
+
+#[test]
+fn check_ordinal() {
+    let cases = [
+        (1, "첫째", "첫 번째"),
+        (2, "둘째", "두 번째"),
+        (3, "셋째", "세 번째"),
+        (4, "넷째", "네 번째"),
+        (5, "다섯째", "다섯 번째"),
+        (11, "열한째", "열한 번째"),
+        (13, "열셋째", "열세 번째"),
+        (20, "스무째", "스무 번째"),
+        (22, "스물두째", "스물두 번째"),
+    ];
+
+    for (n, plain, counter) in cases.iter() {
+        assert_eq!(Ok(plain.to_string()), ordinal(*n, false));
+        assert_eq!(Ok(counter.to_string()), ordinal(*n, true));
+    }
+    assert_eq!(Err(HangeulError::OutOfRange), ordinal(0, true));
+}
+
+#[test]
+fn check_parse() {
+    let cases = [
+        ("영", 0),
+        ("십", 10),
+        ("삼천오백", 3500),
+        ("이십일만 오천", 215_000),
+        ("만 이천삼백사십오", 12345),
+        ("일억 이천만", 120_000_000),
+        ("억", 100_000_000),
+        ("천만", 10_000_000),
+        ("1억 2천만", 120_000_000),
+        ("3만5천", 35_000),
+        ("2,345만", 23_450_000),
+        ("12345", 12345),
+        ("스물다섯", 25),
+        ("열", 10),
+        ("아흔아홉", 99),
+        ("일흔", 70),
+        ("하나", 1),
+        ("마이너스 십", -10),
+        ("-3천", -3000),
+        ("-9223372036854775808", i64::MIN),
+    ];
+
+    for (written, expected) in cases.iter() {
+        assert_eq!(Ok(*expected), parse(written), "{}", written);
+    }
+}
+
+#[test]
+fn check_parse_roundtrip() {
+    for n in [
+        1,
+        19,
+        305,
+        4096,
+        10001,
+        99_999_999,
+        1_234_567_890_123,
+        i64::MAX,
+        i64::MIN + 1,
+    ] {
+        assert_eq!(Ok(n), parse(&sino(n)));
+    }
+}
+
+#[test]
+fn check_parse_errors() {
+    assert_eq!(Err(HangeulError::InvalidNumber(0)), parse(""));
+    assert_eq!(Err(HangeulError::InvalidNumber(1)), parse("일이"));
+    assert_eq!(Err(HangeulError::InvalidNumber(1)), parse("십백"));
+    assert_eq!(Err(HangeulError::InvalidNumber(3)), parse("삼천 개"));
+    assert_eq!(Err(HangeulError::InvalidNumber(2)), parse("만 억"));
+    assert_eq!(Err(HangeulError::InvalidNumber(2)), parse("스물열"));
+    assert_eq!(Err(HangeulError::InvalidNumber(2)), parse("스물삼"));
+    assert_eq!(Err(HangeulError::InvalidNumber(2)), parse("12천"));
+    assert_eq!(Err(HangeulError::OutOfRange), parse("천경"));
+    assert_eq!(
+        Err(HangeulError::OutOfRange),
+        parse("9천18446744073709551615")
+    );
+    assert_eq!(
+        Err(HangeulError::OutOfRange),
+        parse("천18446744073709551615만")
+    );
+    assert_eq!(Err(HangeulError::OutOfRange), parse("9223372036854775808"));
+    assert_eq!(Err(HangeulError::OutOfRange), parse("-9223372036854775809"));
+}
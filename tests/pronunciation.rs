@@ -0,0 +1,107 @@
+extern crate hangeul;
+use hangeul::models::{Choseong, Jongseong};
+use hangeul::pronunciation::*;
+
+fn rules(content: &str) -> Vec<Rule> {
+    changes(content).iter().map(|change| change.rule).collect()
+}
+
+#[test]
+fn check_pronounce() {
+    let cases = [
+        ("옷이", "오시"),
+        ("옷", "옫"),
+        ("부엌", "부억"),
+        ("앞", "압"),
+        ("국물", "궁물"),
+        ("종로", "종노"),
+        ("독립", "동닙"),
+        ("신라", "실라"),
+        ("칼날", "칼랄"),
+        ("같이", "가치"),
+        ("굳이", "구지"),
+        ("굳히다", "구치다"),
+        ("좋고", "조코"),
+        ("축하", "추카"),
+        ("않고", "안코"),
+        ("좋아", "조아"),
+        ("많이", "마니"),
+        ("닿소", "다쏘"),
+        ("놓는", "논는"),
+        ("학교", "학꾜"),
+        ("옷도", "옫또"),
+        ("닭", "닥"),
+        ("닭이", "달기"),
+        ("닭도", "닥또"),
+        ("값이", "갑씨"),
+        ("넋이", "넉씨"),
+        ("넓게", "널께"),
+        ("밟고", "밥꼬"),
+        ("밟는", "밤는"),
+        ("여덟", "여덜"),
+        ("있어", "이써"),
+        ("강아지", "강아지"),
+        ("한국어", "한구거"),
+    ];
+
+    for (written, pronounced) in cases.iter() {
+        assert_eq!(*pronounced, pronounce(written), "{}", written);
+    }
+}
+
+#[test]
+fn check_rules() {
+    let cases = [
+        ("옷이", vec![Rule::Liaison]),
+        ("옷", vec![Rule::Neutralization]),
+        ("국물", vec![Rule::Nasalization]),
+        ("신라", vec![Rule::Lateralization]),
+        ("같이", vec![Rule::Palatalization]),
+        ("굳히다", vec![Rule::Aspiration, Rule::Palatalization]),
+        ("좋고", vec![Rule::Aspiration]),
+        ("학교", vec![Rule::Tensification]),
+        ("닭", vec![Rule::Simplification]),
+        ("닭이", vec![Rule::Liaison]),
+        ("값이", vec![Rule::Liaison, Rule::Tensification]),
+        ("옷도", vec![Rule::Neutralization, Rule::Tensification]),
+        ("않아", vec![Rule::HieuhDeletion, Rule::Liaison]),
+        ("강아지", vec![]),
+    ];
+
+    for (written, expected) in cases.iter() {
+        assert_eq!(*expected, rules(written), "{}", written);
+    }
+}
+
+#[test]
+fn check_changes() {
+    let fired = changes("닭이 국물");
+    assert_eq!(
+        vec![
+            Change {
+                position: 0,
+                rule: Rule::Liaison,
+                before: (Some(Jongseong::RieulGiyeok), Some(Choseong::Ieung)),
+                after: (Some(Jongseong::Rieul), Some(Choseong::Giyeok)),
+            },
+            Change {
+                position: 3,
+                rule: Rule::Nasalization,
+                before: (Some(Jongseong::Giyeok), Some(Choseong::Mieum)),
+                after: (Some(Jongseong::Ieung), Some(Choseong::Mieum)),
+            },
+        ],
+        fired
+    );
+
+    let end = &changes("앞")[0];
+    assert_eq!((Some(Jongseong::Pieup), None), end.before);
+    assert_eq!((Some(Jongseong::Bieup), None), end.after);
+}
+
+#[test]
+fn check_mixed_content() {
+    assert_eq!("궁무를 머거요!", pronounce("국물을 먹어요!"));
+    assert_eq!("PC 2대", pronounce("PC 2대"));
+    assert_eq!(2, changes("a 국물")[0].position);
+}
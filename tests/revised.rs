@@ -0,0 +1,189 @@
+extern crate hangeul;
+use hangeul::revised::*;
+
+fn proper(content: &str) -> String {
+    let options = Options {
+        capitalize: true,
+        ..Options::default()
+    };
+    romanize_with(content, options)
+}
+
+#[test]
+fn check_romanize() {
+    let cases = [
+        ("한글", "hangeul"),
+        ("구미", "gumi"),
+        ("영동", "yeongdong"),
+        ("백암", "baegam"),
+        ("옥천", "okcheon"),
+        ("합덕", "hapdeok"),
+        ("호법", "hobeop"),
+        ("월곶", "wolgot"),
+        ("벚꽃", "beotkkot"),
+        ("한밭", "hanbat"),
+        ("구리", "guri"),
+        ("설악", "seorak"),
+        ("칠곡", "chilgok"),
+        ("임실", "imsil"),
+        ("울릉", "ulleung"),
+        ("의정부", "uijeongbu"),
+        ("광희문", "gwanghuimun"),
+    ];
+
+    for (hangeul, romanized) in cases.iter() {
+        assert_eq!(*romanized, romanize(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_sound_changes() {
+    let cases = [
+        ("백마", "baengma"),
+        ("종로", "jongno"),
+        ("왕십리", "wangsimni"),
+        ("별내", "byeollae"),
+        ("신라", "silla"),
+        ("대관령", "daegwallyeong"),
+        ("해돋이", "haedoji"),
+        ("같이", "gachi"),
+        ("굳히다", "guchida"),
+        ("좋고", "joko"),
+        ("놓다", "nota"),
+        ("낳지", "nachi"),
+        ("묵호", "mukho"),
+        ("집현전", "jiphyeonjeon"),
+        ("압구정", "apgujeong"),
+        ("낙동강", "nakdonggang"),
+        ("샛별", "saetbyeol"),
+        ("한국어", "hangugeo"),
+        ("닭이", "dalgi"),
+        ("좋아", "joa"),
+        ("않는", "anneun"),
+        ("독립문", "dongnimmun"),
+    ];
+
+    for (hangeul, romanized) in cases.iter() {
+        assert_eq!(*romanized, romanize(hangeul), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_capitalize_and_hyphenate() {
+    assert_eq!("Busan", proper("부산"));
+    assert_eq!("Seoul, Jeju", proper("서울, 제주"));
+    assert_eq!("Hong Gildong", proper("홍 길동"));
+
+    let options = Options {
+        capitalize: true,
+        hyphenate: true,
+        ..Options::default()
+    };
+    assert_eq!("Jung-ang", romanize_with("중앙", options));
+    assert_eq!("Ban-gudae", romanize_with("반구대", options));
+    assert_eq!("Se-un", romanize_with("세운", options));
+    assert_eq!("Hae-undae", romanize_with("해운대", options));
+    assert_eq!("Busan", romanize_with("부산", options));
+    assert_eq!("Jungang", proper("중앙"));
+}
+
+#[test]
+fn check_strict() {
+    let strict = Options {
+        strict: true,
+        ..Options::default()
+    };
+    let cases = [
+        ("집", "jib"),
+        ("짚", "jip"),
+        ("밖", "bakk"),
+        ("값", "gabs"),
+        ("붓꽃", "buskkoch"),
+        ("먹는", "meogneun"),
+        ("독립", "doglib"),
+        ("문리", "munli"),
+        ("물엿", "mul-yeos"),
+        ("굳이", "gud-i"),
+        ("좋다", "johda"),
+        ("가곡", "gagog"),
+        ("조랑말", "jolangmal"),
+        ("없었습니다", "eobs-eoss-seubnida"),
+    ];
+
+    for (hangeul, romanized) in cases.iter() {
+        assert_eq!(*romanized, romanize_with(hangeul, strict), "{}", hangeul);
+    }
+}
+
+#[test]
+fn check_mixed_content() {
+    assert_eq!("seoul 2024!", romanize("서울 2024!"));
+    assert_eq!("K-pop", romanize("K-pop"));
+    assert_eq!("ㅋㅋ", romanize("ㅋㅋ"));
+}
+
+#[test]
+fn check_to_hangeul() {
+    let cases = [
+        ("gangnam", "강남"),
+        ("busan", "부산"),
+        ("kimchi", "김치"),
+        ("Seoul", "서울"),
+        ("hangeul", "한글"),
+        ("jung-ang", "중앙"),
+        ("Ban-gudae", "반구대"),
+        ("geu", "그"),
+        ("daejeon", "대전"),
+        ("Jeju", "제주"),
+    ];
+
+    for (romanized, hangeul) in cases.iter() {
+        assert_eq!(*hangeul, to_hangeul(romanized)[0], "{}", romanized);
+    }
+}
+
+#[test]
+fn check_to_hangeul_candidates() {
+    let candidates = to_hangeul("jungang");
+    assert!(candidates.contains(&"중앙".to_string()));
+    assert!(candidates.contains(&"준강".to_string()));
+
+    assert!(to_hangeul("jongno").contains(&"종로".to_string()));
+    assert!(to_hangeul("silla").contains(&"신라".to_string()));
+    assert!(to_hangeul("baengma").contains(&"백마".to_string()));
+    assert!(to_hangeul("hangugeo").contains(&"한국어".to_string()));
+    assert!(to_hangeul("doglib").contains(&"독립".to_string()));
+    assert!(to_hangeul("hangeul").len() <= 10);
+
+    // assimilation only hides spellings that are romanized the same
+    for romanized in ["jongno", "silla", "simni", "baengma"].iter() {
+        for candidate in to_hangeul(romanized) {
+            assert_eq!(*romanized, romanize(&candidate), "{}", candidate);
+        }
+    }
+}
+
+#[test]
+fn check_to_hangeul_long() {
+    // the unassimilated spellings are pruned at every syllable boundary
+    let candidates = to_hangeul(&"jongno".repeat(10));
+    assert!(!candidates.is_empty());
+    assert!(candidates.len() <= 10);
+}
+
+#[test]
+fn check_to_hangeul_mixed() {
+    assert_eq!("서울 2024!", to_hangeul("seoul 2024!")[0]);
+    assert_eq!("x", to_hangeul("x")[0]);
+}
+
+#[test]
+fn check_roundtrip() {
+    for word in ["한글", "부산", "대한민국", "제주도", "안녕하세요"].iter() {
+        assert!(
+            to_hangeul(&romanize(word)).contains(&word.to_string()),
+            "{}",
+            word
+        );
+    }
+}
@@ -0,0 +1,155 @@
+extern crate hangeul;
+use hangeul::search::*;
+
+const PREFIX: Options = Options {
+    mode: Mode::Prefix,
+    incremental: false,
+};
+const SUBSTRING: Options = Options {
+    mode: Mode::Substring,
+    incremental: false,
+};
+const SUBSEQUENCE: Options = Options {
+    mode: Mode::Subsequence,
+    incremental: false,
+};
+const INCREMENTAL: Options = Options {
+    mode: Mode::Prefix,
+    incremental: true,
+};
+
+#[test]
+fn check_prefix() {
+    let cases = [
+        ("ㅎㄱ", "한글", Some(vec![(0, 2)])),
+        ("ㅎ", "한글", Some(vec![(0, 1)])),
+        ("한ㄱ", "한글", Some(vec![(0, 2)])),
+        ("한글", "한글날", Some(vec![(0, 2)])),
+        ("ㄲ", "꽃", Some(vec![(0, 1)])),
+        ("ㄱ", "꽃", None),
+        ("ㄱㅎ", "한글", None),
+        ("ㅎㄱㄴ", "한글", None),
+        ("ㄱ", "대한국", None),
+        ("", "한글", Some(vec![])),
+    ];
+
+    for (query, candidate, spans) in cases.iter() {
+        assert_eq!(
+            *spans,
+            find(query, candidate, PREFIX),
+            "{} {}",
+            query,
+            candidate
+        );
+    }
+}
+
+#[test]
+fn check_substring() {
+    let cases = [
+        ("ㅎㄱ", "대한국", Some(vec![(1, 3)])),
+        ("ㅁㄱ", "대한민국", Some(vec![(2, 4)])),
+        ("민ㄱ", "대한민국", Some(vec![(2, 4)])),
+        ("ㅎㄱ", "한조국", None),
+        ("ㄱ", "ㄱ", Some(vec![(0, 1)])),
+    ];
+
+    for (query, candidate, spans) in cases.iter() {
+        assert_eq!(
+            *spans,
+            find(query, candidate, SUBSTRING),
+            "{} {}",
+            query,
+            candidate
+        );
+    }
+}
+
+#[test]
+fn check_subsequence() {
+    let cases = [
+        ("ㅎㄱ", "한조국", Some(vec![(0, 1), (2, 3)])),
+        ("ㅎㄱㄷ", "홍길동", Some(vec![(0, 3)])),
+        ("ㅎㄷ", "홍길동", Some(vec![(0, 1), (2, 3)])),
+        ("ㄷㅎ", "홍길동", None),
+        ("ㄱㄷ", "김 대리", Some(vec![(0, 1), (2, 3)])),
+    ];
+
+    for (query, candidate, spans) in cases.iter() {
+        assert_eq!(
+            *spans,
+            find(query, candidate, SUBSEQUENCE),
+            "{} {}",
+            query,
+            candidate
+        );
+    }
+}
+
+#[test]
+fn check_mixed_content() {
+    assert!(is_match("kim", "Kim 민수", PREFIX));
+    assert!(is_match("ㅁㅅ", "Kim 민수", SUBSTRING));
+    assert_eq!(Some(vec![(4, 6)]), find("ㅁㅅ", "Kim 민수", SUBSTRING));
+    assert!(!is_match("ㅁㅅ", "Kim 민수", PREFIX));
+    assert!(is_match("ㅇㄴ", "안녕하세요", Options::default()));
+}
+
+#[test]
+fn check_incremental() {
+    let cases = [
+        ("한그", "한글", Some(vec![(0, 2)])),
+        ("하", "한", Some(vec![(0, 1)])),
+        ("하", "할머니", Some(vec![(0, 1)])),
+        ("고", "과일", Some(vec![(0, 1)])),
+        ("고", "곽", Some(vec![(0, 1)])),
+        ("그", "의자", None),
+        ("으", "의자", Some(vec![(0, 1)])),
+        ("갈", "갉다", Some(vec![(0, 1)])),
+        ("한", "한국", Some(vec![(0, 1)])),
+        ("한", "할", None),
+        ("하", "허", None),
+        ("과", "고", None),
+        ("ㅎ그", "한글", Some(vec![(0, 2)])),
+        ("간", "가나다", Some(vec![(0, 2)])),
+        ("닭", "달가", Some(vec![(0, 2)])),
+        ("닭", "달기", Some(vec![(0, 2)])),
+        ("간", "가다", None),
+        ("닭", "다가", None),
+        ("간", "가", None),
+        ("", "한글", Some(vec![])),
+    ];
+
+    for (query, candidate, spans) in cases.iter() {
+        assert_eq!(
+            *spans,
+            find(query, candidate, INCREMENTAL),
+            "{} {}",
+            query,
+            candidate
+        );
+    }
+
+    // only the last syllable is still being typed
+    assert!(!is_match("하그", "한글", INCREMENTAL));
+    assert!(!is_match("한그", "한글", PREFIX));
+
+    let substring = Options {
+        incremental: true,
+        ..SUBSTRING
+    };
+    assert_eq!(Some(vec![(2, 4)]), find("민구", "대한민국", substring));
+    assert_eq!(Some(vec![(1, 4)]), find("한간", "대한가나", substring));
+    let subsequence = Options {
+        incremental: true,
+        ..SUBSEQUENCE
+    };
+    assert_eq!(
+        Some(vec![(0, 1), (2, 3)]),
+        find("ㅎ도", "홍길동", subsequence)
+    );
+    assert_eq!(
+        Some(vec![(0, 1), (2, 4)]),
+        find("ㅎ간", "한조가나", subsequence)
+    );
+}
@@ -0,0 +1,101 @@
+extern crate hangeul;
+use hangeul::constants::{SYLLABLE_END, SYLLABLE_START};
+use hangeul::transliteration::*;
+
+const SYSTEMS: [System; 2] = [System::Yale, System::Iso11941];
+
+fn syllables() -> Vec<char> {
+    (SYLLABLE_START..=SYLLABLE_END)
+        .filter_map(std::char::from_u32)
+        .collect()
+}
+
+#[test]
+fn check_yale() {
+    let cases = [
+        ("한글", "hankul"),
+        ("서울", "sewul"),
+        ("물", "mwul"),
+        ("의자", "uyca"),
+        ("괜찮아", "kwaynchanh.a"),
+        ("읽다", "ilkta"),
+        ("값어치", "kaps.echi"),
+        ("좋다", "cohta"),
+        ("익히", "ik.hi"),
+        ("한국어", "hankwuk.e"),
+        ("항아리", "hangali"),
+        ("아이", "ai"),
+        ("아야", "a.ya"),
+    ];
+
+    for (hangeul, yale) in cases.iter() {
+        assert_eq!(*yale, transliterate(hangeul, System::Yale), "{}", hangeul);
+        assert_eq!(*hangeul, to_hangeul(yale, System::Yale));
+    }
+}
+
+#[test]
+fn check_iso_11941() {
+    let cases = [
+        ("한글", "hangeul"),
+        ("서울", "seoul"),
+        ("의자", "yija"),
+        ("천리", "ceonri"),
+        ("독립", "dogrib"),
+        ("값", "gabs"),
+        ("항우", "hang-u"),
+        ("익히", "ighi"),
+        ("에우", "e-u"),
+    ];
+
+    for (hangeul, iso) in cases.iter() {
+        assert_eq!(
+            *iso,
+            transliterate(hangeul, System::Iso11941),
+            "{}",
+            hangeul
+        );
+        assert_eq!(*hangeul, to_hangeul(iso, System::Iso11941));
+    }
+}
+
+#[test]
+fn check_every_syllable_roundtrip() {
+    for system in SYSTEMS.iter() {
+        for c in syllables() {
+            let written = transliterate(&c.to_string(), *system);
+            assert_eq!(c.to_string(), to_hangeul(&written, *system), "{}", written);
+        }
+    }
+}
+
+#[test]
+fn check_words_roundtrip() {
+    let all = syllables();
+    let mut seed: usize = 1;
+    let mut next = || {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        all[(seed >> 33) % all.len()]
+    };
+
+    for system in SYSTEMS.iter() {
+        for _ in 0..20_000 {
+            let word: String = (0..3).map(|_| next()).collect();
+            let written = transliterate(&word, *system);
+            assert_eq!(word, to_hangeul(&written, *system), "{}", written);
+        }
+    }
+}
+
+#[test]
+fn check_mixed_content() {
+    for system in SYSTEMS.iter() {
+        let content = "한국어 (English), 2024. 끝.";
+        assert_eq!(
+            content,
+            to_hangeul(&transliterate(content, *system), *system)
+        );
+    }
+    assert_eq!("hankwuk.e.", transliterate("한국어.", System::Yale));
+    assert_eq!("xyz 한국", to_hangeul("xyz hankwuk", System::Yale));
+}